@@ -0,0 +1,114 @@
+//! # Borsh integration
+//!
+//! `ArkScale<T, U>`, `ArkScaleRef<T, U>`, and `ArkScaleLen<T, L>` implement
+//! Borsh by adapting Borsh's `Read` and `Write` to arkworks' exactly as `rw`
+//! does for SCALE, so a value has the same bytes under Borsh and SCALE,
+//! except that each codec prefixes collections with its own lengths.
+
+use super::*;
+use ::borsh::{io as bio, BorshDeserialize, BorshSerialize};
+
+#[cfg(feature = "std")]
+fn borsh_io_error_to_ark_io_error(error: bio::Error) -> io::Error {
+    error
+}
+
+#[cfg(not(feature = "std"))]
+fn borsh_io_error_to_ark_io_error(error: bio::Error) -> io::Error {
+    io::Error::new(io::ErrorKind::Other, ark_std::format!("{}", error))
+}
+
+#[cfg(feature = "std")]
+fn ark_io_error_to_borsh_io_error(error: io::Error) -> bio::Error {
+    error
+}
+
+#[cfg(not(feature = "std"))]
+fn ark_io_error_to_borsh_io_error(error: io::Error) -> bio::Error {
+    bio::Error::new(bio::ErrorKind::Other, ark_std::format!("Arkworks deserialization io error: {}", error))
+}
+
+pub fn ark_error_to_borsh_error(error: SerializationError) -> bio::Error {
+    use SerializationError::*;
+    let invalid = |msg: &str| bio::Error::new(bio::ErrorKind::InvalidData, msg);
+    match error {
+        NotEnoughSpace => invalid("Arkworks deserialization failed: NotEnoughSpace"),
+        InvalidData => invalid("Arkworks deserialization failed: InvalidData"),
+        UnexpectedFlags => invalid("Arkworks deserialization failed: UnexpectedFlags"),
+        IoError(io_error) => ark_io_error_to_borsh_io_error(io_error),
+    }
+}
+
+/// Borsh `Read` wrapped as Arkworks' `Read`
+pub struct BorshReadAsRead<'a, R: bio::Read + ?Sized>(pub &'a mut R);
+
+impl<'a, R: bio::Read + ?Sized> Read for BorshReadAsRead<'a, R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.0.read(buf).map_err(borsh_io_error_to_ark_io_error)
+    }
+
+    fn read_exact(&mut self, buf: &mut [u8]) -> io::Result<()> {
+        self.0.read_exact(buf).map_err(borsh_io_error_to_ark_io_error)
+    }
+}
+
+/// Borsh `Write` wrapped as Arkworks' `Write`
+pub struct BorshWriteAsWrite<'a, W: bio::Write + ?Sized>(pub &'a mut W);
+
+impl<'a, W: bio::Write + ?Sized> Write for BorshWriteAsWrite<'a, W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0.write(buf).map_err(borsh_io_error_to_ark_io_error)
+    }
+
+    fn write_all(&mut self, buf: &[u8]) -> io::Result<()> {
+        self.0.write_all(buf).map_err(borsh_io_error_to_ark_io_error)
+    }
+
+    fn flush(&mut self) -> ArkResult<()> {
+        self.0.flush().map_err(borsh_io_error_to_ark_io_error)
+    }
+}
+
+impl<T: CanonicalSerialize, const U: Usage> BorshSerialize for ArkScale<T, U> {
+    fn serialize<W: bio::Write>(&self, writer: &mut W) -> bio::Result<()> {
+        self.0
+            .serialize_with_mode(BorshWriteAsWrite(writer), is_compressed(U))
+            .map_err(ark_error_to_borsh_error)
+    }
+}
+
+impl<'a, T: CanonicalSerialize, const U: Usage> BorshSerialize for ArkScaleRef<'a, T, U> {
+    fn serialize<W: bio::Write>(&self, writer: &mut W) -> bio::Result<()> {
+        self.0
+            .serialize_with_mode(BorshWriteAsWrite(writer), is_compressed(U))
+            .map_err(ark_error_to_borsh_error)
+    }
+}
+
+impl<T: CanonicalDeserialize, const U: Usage> BorshDeserialize for ArkScale<T, U> {
+    fn deserialize_reader<R: bio::Read>(reader: &mut R) -> bio::Result<Self> {
+        <T as CanonicalDeserialize>::deserialize_with_mode(
+            BorshReadAsRead(reader),
+            is_compressed(U),
+            is_validated(U),
+        )
+        .map(ArkScale)
+        .map_err(ark_error_to_borsh_error)
+    }
+}
+
+impl<T: CanonicalSerialize, const L: usize> BorshSerialize for ArkScaleLen<T, L> {
+    fn serialize<W: bio::Write>(&self, writer: &mut W) -> bio::Result<()> {
+        self.0
+            .serialize_with_mode(BorshWriteAsWrite(writer), Compress::Yes)
+            .map_err(ark_error_to_borsh_error)
+    }
+}
+
+impl<T: CanonicalDeserialize, const L: usize> BorshDeserialize for ArkScaleLen<T, L> {
+    fn deserialize_reader<R: bio::Read>(reader: &mut R) -> bio::Result<Self> {
+        <T as CanonicalDeserialize>::deserialize_with_mode(BorshReadAsRead(reader), Compress::Yes, Validate::Yes)
+            .map(ArkScaleLen)
+            .map_err(ark_error_to_borsh_error)
+    }
+}
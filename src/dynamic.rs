@@ -0,0 +1,214 @@
+//! # Dynamic encoding and decoding guided by type information
+//!
+//! Dynamic clients like subxt encode and decode through `scale-encode`'s
+//! `EncodeAsType` and `scale-decode`'s `DecodeAsType`, given a type id
+//! into some registry.  `ArkScale<T, U>` describes itself in `TypeInfo`
+//! as a byte array of `T::max_encoded_len`, so we check that the target
+//! type id resolves to `[u8; T::max_encoded_len]` before using our static
+//! SCALE encoding or decoding.
+//!
+//! This check is weaker than matching the type itself:  Type resolvers
+//! expose no paths or docs for arrays, so any other type which also
+//! describes itself as a byte array of the same length passes, like
+//! another curve's point of the same encoded length, or a plain
+//! `[u8; N]`.  We cannot distinguish these by type information alone.
+//!
+//! With the `scale-value` feature, we also convert points and field
+//! elements into `scale_value::Value`s which show their coordinates, as
+//! big-endian hex integers, with extension fields flattened into their
+//! base prime field coefficients.
+
+use ark_std::marker::PhantomData;
+
+use scale_type_resolver::{Primitive, ResolvedTypeVisitor, TypeResolver, UnhandledKind};
+
+use crate::{
+    is_compressed,
+    scale::{Decode, Encode},
+    ArkScale, ArkScaleMaxEncodedLen, Usage,
+};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+
+/// Yields the element type id of arrays of length `len`.
+struct ArrayOfLen<Id> {
+    len: usize,
+    _id: PhantomData<Id>,
+}
+
+impl<'r, Id: scale_type_resolver::TypeId + 'static> ResolvedTypeVisitor<'r> for ArrayOfLen<Id> {
+    type TypeId = Id;
+    type Value = Option<Id>;
+
+    fn visit_unhandled(self, _kind: UnhandledKind) -> Option<Id> {
+        None
+    }
+
+    fn visit_array(self, type_id: Id, len: usize) -> Option<Id> {
+        (len == self.len).then_some(type_id)
+    }
+}
+
+/// Checks for the `u8` primitive.
+struct IsU8<Id>(PhantomData<Id>);
+
+impl<'r, Id: scale_type_resolver::TypeId + 'static> ResolvedTypeVisitor<'r> for IsU8<Id> {
+    type TypeId = Id;
+    type Value = bool;
+
+    fn visit_unhandled(self, _kind: UnhandledKind) -> bool {
+        false
+    }
+
+    fn visit_primitive(self, primitive: Primitive) -> bool {
+        primitive == Primitive::U8
+    }
+}
+
+/// Does `type_id` resolve to `[u8; len]` in `types`?
+fn is_byte_array<R: TypeResolver>(type_id: R::TypeId, types: &R, len: usize) -> bool {
+    let array = ArrayOfLen { len, _id: PhantomData };
+    let Ok(Some(elem)) = types.resolve_type(type_id, array) else {
+        return false;
+    };
+    matches!(types.resolve_type(elem, IsU8(PhantomData)), Ok(true))
+}
+
+/// Does `type_id` resolve to the shape of `ArkScale<T, U>`, namely
+/// `[u8; len]`?  We cannot check the path, so this accepts any byte
+/// array of the same length.
+fn is_ark_scale<T, const U: Usage, R>(type_id: R::TypeId, types: &R) -> bool
+where
+    T: ArkScaleMaxEncodedLen,
+    R: TypeResolver,
+{
+    is_byte_array(type_id, types, T::max_encoded_len(is_compressed(U)))
+}
+
+const WRONG_TYPE: &str = "ArkScale type id does not resolve to a byte array of its encoded length";
+
+impl<T, const U: Usage> scale_encode::EncodeAsType for ArkScale<T, U>
+where
+    T: 'static + CanonicalSerialize + ArkScaleMaxEncodedLen,
+{
+    fn encode_as_type_to<R: TypeResolver>(
+        &self,
+        type_id: R::TypeId,
+        types: &R,
+        out: &mut scale_encode::Vec<u8>,
+    ) -> Result<(), scale_encode::Error> {
+        if !is_ark_scale::<T, U, R>(type_id, types) {
+            return Err(scale_encode::Error::custom_str(WRONG_TYPE));
+        }
+        self.encode_to(out);
+        Ok(())
+    }
+}
+
+/// `scale-decode` visitor for `ArkScale<T, U>`
+pub struct ArkScaleVisitor<T, const U: Usage, R>(PhantomData<(T, R)>);
+
+impl<T, const U: Usage, R> scale_decode::Visitor for ArkScaleVisitor<T, U, R>
+where
+    T: 'static + CanonicalDeserialize + ArkScaleMaxEncodedLen,
+    R: TypeResolver,
+{
+    type Value<'scale, 'resolver> = ArkScale<T, U>;
+    type Error = scale_decode::Error;
+    type TypeResolver = R;
+
+    fn unchecked_decode_as_type(
+        self,
+        input: &mut &[u8],
+        type_id: R::TypeId,
+        types: &R,
+    ) -> scale_decode::visitor::DecodeAsTypeResult<Self, Result<ArkScale<T, U>, scale_decode::Error>> {
+        use scale_decode::visitor::DecodeAsTypeResult::Decoded;
+        if !is_ark_scale::<T, U, R>(type_id, types) {
+            return Decoded(Err(scale_decode::Error::custom_str(WRONG_TYPE)));
+        }
+        Decoded(ArkScale::<T, U>::decode(input).map_err(Into::into))
+    }
+}
+
+impl<T, const U: Usage> scale_decode::IntoVisitor for ArkScale<T, U>
+where
+    T: 'static + CanonicalDeserialize + ArkScaleMaxEncodedLen,
+{
+    type AnyVisitor<R: TypeResolver> = ArkScaleVisitor<T, U, R>;
+
+    fn into_visitor<R: TypeResolver>() -> Self::AnyVisitor<R> {
+        ArkScaleVisitor(PhantomData)
+    }
+}
+
+#[cfg(feature = "scale-value")]
+pub use value::ToValue;
+
+#[cfg(feature = "scale-value")]
+mod value {
+    use ark_std::vec::Vec;
+
+    use ark_ec::models::{short_weierstrass as sw, twisted_edwards as te};
+    use ark_ff::{fields::models::*, BigInteger, Field, PrimeField};
+    use scale_value::Value;
+
+    use crate::{hex, ArkScale, Usage};
+
+    /// Arkworks types shown as `scale_value::Value`s
+    pub trait ToValue {
+        fn to_value(&self) -> Value;
+    }
+
+    fn field_to_value<F: Field>(f: &F) -> Value {
+        let mut coeffs: Vec<Value> = f
+            .to_base_prime_field_elements()
+            .map(|c| Value::string(hex::to_hex(&c.into_bigint().to_bytes_be())))
+            .collect();
+        if coeffs.len() == 1 {
+            coeffs.pop().unwrap()
+        } else {
+            Value::unnamed_composite(coeffs)
+        }
+    }
+
+    impl<P: FpConfig<N>, const N: usize> ToValue for Fp<P, N> {
+        fn to_value(&self) -> Value {
+            field_to_value(self)
+        }
+    }
+
+    impl<P: QuadExtConfig> ToValue for QuadExtField<P> {
+        fn to_value(&self) -> Value {
+            field_to_value(self)
+        }
+    }
+
+    impl<P: CubicExtConfig> ToValue for CubicExtField<P> {
+        fn to_value(&self) -> Value {
+            field_to_value(self)
+        }
+    }
+
+    /// Either `Identity` or `Point { x, y }`
+    impl<C: sw::SWCurveConfig> ToValue for sw::Affine<C> {
+        fn to_value(&self) -> Value {
+            if self.infinity {
+                return Value::unnamed_variant("Identity", []);
+            }
+            Value::named_variant("Point", [("x", field_to_value(&self.x)), ("y", field_to_value(&self.y))])
+        }
+    }
+
+    /// Always `{ x, y }`, since the identity has affine coordinates
+    impl<C: te::TECurveConfig> ToValue for te::Affine<C> {
+        fn to_value(&self) -> Value {
+            Value::named_composite([("x", field_to_value(&self.x)), ("y", field_to_value(&self.y))])
+        }
+    }
+
+    impl<T: ToValue, const U: Usage> From<ArkScale<T, U>> for Value {
+        fn from(t: ArkScale<T, U>) -> Value {
+            t.0.to_value()
+        }
+    }
+}
@@ -1,9 +1,37 @@
+use ark_serialize::{CanonicalDeserialize, Compress, Validate};
 
-use ark_serialize::Compress;
+use crate::{
+    is_compressed, rw::*, ArkScale, ArkScaleMaxEncodedLen, Usage, WIRE,
+    scale::{self, Decode, Input},
+}; // ArkScaleRef,ConstEncodedLen
 
-use crate::ArkScaleMaxEncodedLen; // ArkScale,ArkScaleRef,ConstEncodedLen
+use ark_ec::{
+    models::{short_weierstrass as sw, twisted_edwards as te},
+    AffineRepr,
+};
 
-use ark_ec::models::{short_weierstrass as sw, twisted_edwards as te};
+pub mod eip2537;
+pub use eip2537::Eip2537;
+
+pub mod zcash;
+pub use zcash::Zcash;
+
+pub mod sec1;
+pub use sec1::Sec1;
+
+pub mod rfc8032;
+pub use rfc8032::Rfc8032;
+
+pub mod bip340;
+pub use bip340::XOnly;
+
+pub mod torus;
+pub use torus::{Torus, Torus6};
+
+pub mod ristretto;
+pub use ristretto::RistrettoScale;
+
+mod pairing;
 
 impl<P: sw::SWCurveConfig> ArkScaleMaxEncodedLen for sw::Affine<P> {
     #[inline]
@@ -18,3 +46,110 @@ impl<P: te::TECurveConfig> ArkScaleMaxEncodedLen for te::Affine<P> {
         P::serialized_size(compress)
     }
 }
+
+crate::impl_from_ark_scale!(sw::Affine<C>; C: sw::SWCurveConfig);
+crate::impl_from_ark_scale!(sw::Projective<C>; C: sw::SWCurveConfig);
+crate::impl_from_ark_scale!(te::Affine<C>; C: te::TECurveConfig);
+crate::impl_from_ark_scale!(te::Projective<C>; C: te::TECurveConfig);
+crate::impl_from_ark_scale!(ark_ec::pairing::PairingOutput<P>; P: ark_ec::pairing::Pairing);
+
+/// Decode a twisted Edwards point without arkworks' validation,
+/// but check the curve equation ourselves.
+fn te_decode_on_curve<I, C>(input: &mut I, compress: Compress) -> Result<te::Affine<C>, scale::Error>
+where
+    I: Input,
+    C: te::TECurveConfig,
+{
+    let p = <te::Affine<C> as CanonicalDeserialize>::deserialize_with_mode(
+        InputAsRead(input),
+        compress,
+        Validate::No,
+    )
+    .map_err(ark_error_to_scale_error)?;
+    if !p.is_on_curve() {
+        return Err("Twisted Edwards point is not on the curve".into());
+    }
+    Ok(p)
+}
+
+/// Twisted Edwards point which decodes any point `P` on the curve,
+/// and then multiplies by the cofactor `h`, so decoding yields `[h]P`
+/// in the prime order subgroup.
+///
+/// Decoding CHANGES EVERY NON-IDENTITY POINT, including points already
+/// in the prime order subgroup, so `decode(encode(p)) == [h]p`, not `p`.
+/// Your protocol must therefore be designed around cofactor
+/// multiplication, as with cofactored Ed25519 verification.
+///
+/// We check the curve equation regardless of the `Validate` flag
+/// in `U`, but use its `Compress` flag.
+#[derive(Clone, Eq, PartialEq, Debug)]
+#[repr(transparent)]
+pub struct ClearCofactor<T, const U: Usage = WIRE>(pub T);
+
+impl<T, const U: Usage> From<T> for ClearCofactor<T, U> {
+    fn from(t: T) -> ClearCofactor<T, U> {
+        ClearCofactor(t)
+    }
+}
+
+impl<C: te::TECurveConfig, const U: Usage> Decode for ClearCofactor<te::Affine<C>, U> {
+    fn decode<I: Input>(input: &mut I) -> Result<Self, scale::Error> {
+        te_decode_on_curve::<I, C>(input, is_compressed(U)).map(|p| ClearCofactor(p.clear_cofactor()))
+    }
+}
+
+/// Twisted Edwards point which decodes only points on the curve
+/// without any small order torsion component, so that decoding never
+/// changes the point.
+///
+/// We check the curve equation and subgroup membership regardless of
+/// the `Validate` flag in `U`, but use its `Compress` flag.  We return
+/// distinct errors for points off the curve and points with torsion.
+#[derive(Clone, Eq, PartialEq, Debug)]
+#[repr(transparent)]
+pub struct TorsionFree<T, const U: Usage = WIRE>(pub T);
+
+impl<T, const U: Usage> From<T> for TorsionFree<T, U> {
+    fn from(t: T) -> TorsionFree<T, U> {
+        TorsionFree(t)
+    }
+}
+
+impl<C: te::TECurveConfig, const U: Usage> Decode for TorsionFree<te::Affine<C>, U> {
+    fn decode<I: Input>(input: &mut I) -> Result<Self, scale::Error> {
+        let p = te_decode_on_curve::<I, C>(input, is_compressed(U))?;
+        if !p.is_in_correct_subgroup_assuming_on_curve() {
+            return Err("Twisted Edwards point has a small order torsion component".into());
+        }
+        Ok(TorsionFree(p))
+    }
+}
+
+/// Elliptic curve point which never decodes to the identity,
+/// suitable for public keys and VRF outputs.
+///
+/// We otherwise decode exactly like `ArkScale<T, U>`.
+#[derive(Clone, Eq, PartialEq, Debug)]
+#[repr(transparent)]
+pub struct NonIdentity<T, const U: Usage = WIRE>(pub T);
+
+impl<T, const U: Usage> From<T> for NonIdentity<T, U> {
+    fn from(t: T) -> NonIdentity<T, U> {
+        NonIdentity(t)
+    }
+}
+
+impl<T: AffineRepr, const U: Usage> Decode for NonIdentity<T, U> {
+    fn decode<I: Input>(input: &mut I) -> Result<Self, scale::Error> {
+        let p = <ArkScale<T, U> as Decode>::decode(input)?.0;
+        if p.is_zero() {
+            return Err("Elliptic curve point is the identity".into());
+        }
+        Ok(NonIdentity(p))
+    }
+}
+
+crate::impl_forward_to_ark_scale!(ClearCofactor);
+crate::impl_forward_to_ark_scale!(TorsionFree);
+crate::impl_forward_to_ark_scale!(NonIdentity);
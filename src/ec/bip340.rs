@@ -0,0 +1,136 @@
+//! # BIP340 x-only encoding for short Weierstrass curves
+//!
+//! [BIP340](https://github.com/bitcoin/bips/blob/master/bip-0340.mediawiki)
+//! Schnorr signatures and Taproot represent secp256k1 public keys by
+//! only their big-endian `x` coordinate, implicitly choosing the point
+//! with even `y`.  Any other point with the same `x` encodes identically,
+//! so encoding `-P` yields the encoding of `P`, and decoding always
+//! returns the even `y` point.  `XOnly` therefore compares only `x`
+//! coordinates.
+
+use ark_std::{vec, vec::Vec};
+
+use ark_ec::{models::short_weierstrass as sw, AffineRepr};
+use ark_ff::{BigInteger, PrimeField};
+
+use crate::{
+    ff::{fp_be_len, fp_from_be_bytes, fp_to_be_bytes},
+    scale::{self, Decode, Encode, EncodeLike, Input, MaxEncodedLen, Output},
+};
+use scale_info::TypeInfo;
+
+/// Short Weierstrass point wrapped for serialization by SCALE as
+/// its BIP340 x-only encoding.
+///
+/// The identity has no x-only encoding, so `XOnly::new` rejects it.
+#[derive(Clone, Debug)]
+#[repr(transparent)]
+pub struct XOnly<T>(T);
+
+impl<T: AffineRepr> XOnly<T> {
+    /// Wrap `t` unless it is the identity.
+    pub fn new(t: T) -> Option<XOnly<T>> {
+        t.xy().map(|_| XOnly(t))
+    }
+
+    /// Some point with the wrapped `x` coordinate, which need not
+    /// have even `y` unless decoded.
+    pub fn point(&self) -> &T {
+        &self.0
+    }
+
+    /// Unwrap into the point returned by `point`.
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+/// Compare `x` coordinates, which agrees with the encoding.
+impl<T: AffineRepr> PartialEq for XOnly<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.x() == other.0.x()
+    }
+}
+
+impl<T: AffineRepr> Eq for XOnly<T> {}
+
+/// BIP340's `lift_x`, returning the even `y` point with the given `x`
+/// coordinate, if any.
+pub fn lift_x<C: sw::SWCurveConfig>(x: C::BaseField) -> Option<sw::Affine<C>>
+where
+    C::BaseField: PrimeField,
+{
+    let (a, b) = sw::Affine::<C>::get_ys_from_x_unchecked(x)?;
+    let y = if a.into_bigint().is_even() { a } else { b };
+    Some(sw::Affine::new_unchecked(x, y))
+}
+
+impl<C: sw::SWCurveConfig> EncodeLike for XOnly<sw::Affine<C>> where C::BaseField: PrimeField {}
+
+impl<C: sw::SWCurveConfig> Encode for XOnly<sw::Affine<C>>
+where
+    C::BaseField: PrimeField,
+{
+    fn size_hint(&self) -> usize {
+        fp_be_len::<C::BaseField>()
+    }
+
+    fn encode_to<O: Output + ?Sized>(&self, dest: &mut O) {
+        let x = self.0.x().expect("XOnly::new rejects the identity.");
+        let mut bytes = vec![0u8; fp_be_len::<C::BaseField>()];
+        fp_to_be_bytes(&x, &mut bytes);
+        dest.write(&bytes);
+    }
+
+    fn encoded_size(&self) -> usize {
+        fp_be_len::<C::BaseField>()
+    }
+}
+
+impl<C: sw::SWCurveConfig> Decode for XOnly<sw::Affine<C>>
+where
+    C::BaseField: PrimeField,
+{
+    fn decode<I: Input>(input: &mut I) -> Result<Self, scale::Error> {
+        let mut bytes = vec![0u8; fp_be_len::<C::BaseField>()];
+        input.read(&mut bytes)?;
+        let x = fp_from_be_bytes::<C::BaseField>(&bytes)
+            .ok_or("BIP340 x coordinate exceeds the field size")?;
+        let p = lift_x::<C>(x).ok_or("BIP340 x coordinate is not on the curve")?;
+        if !p.is_in_correct_subgroup_assuming_on_curve() {
+            return Err("BIP340 point is not in the prime order subgroup".into());
+        }
+        Ok(XOnly(p))
+    }
+
+    fn encoded_fixed_size() -> Option<usize> {
+        Some(fp_be_len::<C::BaseField>())
+    }
+}
+
+impl<C: sw::SWCurveConfig> MaxEncodedLen for XOnly<sw::Affine<C>>
+where
+    C::BaseField: PrimeField,
+{
+    #[inline]
+    fn max_encoded_len() -> usize {
+        fp_be_len::<C::BaseField>()
+    }
+}
+
+impl<C: sw::SWCurveConfig> TypeInfo for XOnly<sw::Affine<C>>
+where
+    C::BaseField: PrimeField,
+{
+    type Identity = Self;
+
+    fn type_info() -> scale_info::Type {
+        let path = scale_info::Path::new("XOnly", module_path!());
+        let array_type_def = scale_info::TypeDefArray {
+            len: fp_be_len::<C::BaseField>() as u32,
+            type_param: scale_info::MetaType::new::<u8>(),
+        };
+        let type_def = scale_info::TypeDef::Array(array_type_def);
+        scale_info::Type { path, type_params: Vec::new(), type_def, docs: Vec::new() }
+    }
+}
@@ -0,0 +1,206 @@
+//! # EIP-2537 encoding for BLS12 curves
+//!
+//! [EIP-2537](https://eips.ethereum.org/EIPS/eip-2537) defines the
+//! BLS12-381 precompiles for the EVM, which encode base field elements
+//! as 64 byte big-endian integers, points as uncompressed `x || y` with
+//! the identity being all zeros and no flag bits, and `Fp2` elements
+//! as `c0 || c1`.  Scalars are 32 byte big-endian integers.
+//!
+//! `Eip2537<T>` wraps `bls12::G1Affine`, `bls12::G2Affine`, and scalar
+//! field elements for SCALE in this layout.  We always validate that
+//! points lie in the prime order subgroup and that all integers are
+//! canonical, so unlike the precompiles we reject unreduced scalars.
+//!
+//! We cannot bound upon `Bls12Config` itself, because `G1Affine<P>`
+//! names `P` only through a projection, which leaves `P` unconstrained.
+//! We instead accept curves over 6 limb prime fields or their quadratic
+//! extensions, and 4 limb scalar fields, as in BLS12-381 and BLS12-377,
+//! so other curves and fields fail to compile, instead of panicking.
+
+use ark_std::{vec, vec::Vec};
+
+use ark_ec::{models::short_weierstrass as sw, AffineRepr};
+use ark_ff::{fields::models::*, Field};
+
+use crate::{
+    ff::{ff_from_be_bytes, ff_to_be_bytes, fp_from_be_bytes, fp_to_be_bytes},
+    scale::{self, Decode, Encode, EncodeLike, Input, MaxEncodedLen, Output},
+};
+use scale_info::TypeInfo;
+
+/// Padded width of base prime field elements
+pub const FP_WIDTH: usize = 64;
+
+/// Width of scalars
+pub const SCALAR_WIDTH: usize = 32;
+
+/// Arkworks type wrapped for serialization by SCALE in EIP-2537 layout
+#[derive(Clone, Eq, PartialEq, Debug)]
+#[repr(transparent)]
+pub struct Eip2537<T>(pub T);
+
+impl<T> From<T> for Eip2537<T> {
+    fn from(t: T) -> Eip2537<T> {
+        Eip2537(t)
+    }
+}
+
+fn type_info_array(len: usize) -> scale_info::Type {
+    let path = scale_info::Path::new("Eip2537", module_path!());
+    let array_type_def = scale_info::TypeDefArray {
+        len: len as u32,
+        type_param: scale_info::MetaType::new::<u8>(),
+    };
+    let type_def = scale_info::TypeDef::Array(array_type_def);
+    scale_info::Type { path, type_params: Vec::new(), type_def, docs: Vec::new() }
+}
+
+// Curve points //
+
+/// Base fields of BLS12 curves, either 6 limb prime fields for G1,
+/// or their quadratic extensions for G2.
+pub trait Eip2537BaseField: Field {
+    /// Length of one coordinate
+    const LEN: usize;
+}
+
+impl<P: FpConfig<6>> Eip2537BaseField for Fp<P, 6> {
+    const LEN: usize = FP_WIDTH;
+}
+
+impl<Q: FpConfig<6>, P: QuadExtConfig<BaseField = Fp<Q, 6>>> Eip2537BaseField for QuadExtField<P> {
+    const LEN: usize = 2 * FP_WIDTH;
+}
+
+const fn point_len<C: sw::SWCurveConfig>() -> usize
+where
+    C::BaseField: Eip2537BaseField,
+{
+    2 * <C::BaseField as Eip2537BaseField>::LEN
+}
+
+impl<C: sw::SWCurveConfig> EncodeLike for Eip2537<sw::Affine<C>> where C::BaseField: Eip2537BaseField {}
+
+impl<C: sw::SWCurveConfig> Encode for Eip2537<sw::Affine<C>>
+where
+    C::BaseField: Eip2537BaseField,
+{
+    fn size_hint(&self) -> usize {
+        point_len::<C>()
+    }
+
+    fn encode_to<O: Output + ?Sized>(&self, dest: &mut O) {
+        match self.0.xy() {
+            None => dest.write(&vec![0u8; point_len::<C>()]),
+            Some((x, y)) => {
+                dest.write(&ff_to_be_bytes(&x, FP_WIDTH, false));
+                dest.write(&ff_to_be_bytes(&y, FP_WIDTH, false));
+            }
+        }
+    }
+
+    fn encoded_size(&self) -> usize {
+        point_len::<C>()
+    }
+}
+
+impl<C: sw::SWCurveConfig> Decode for Eip2537<sw::Affine<C>>
+where
+    C::BaseField: Eip2537BaseField,
+{
+    fn decode<I: Input>(input: &mut I) -> Result<Self, scale::Error> {
+        let mut buf = vec![0u8; point_len::<C>()];
+        input.read(&mut buf)?;
+        if buf.iter().all(|b| *b == 0) {
+            return Ok(Eip2537(sw::Affine::identity()));
+        }
+        let err = || scale::Error::from("EIP-2537 base field element is not canonical");
+        let (x, y) = buf.split_at(buf.len() / 2);
+        let x = ff_from_be_bytes::<C::BaseField>(x, FP_WIDTH, false).ok_or_else(err)?;
+        let y = ff_from_be_bytes::<C::BaseField>(y, FP_WIDTH, false).ok_or_else(err)?;
+        let p = sw::Affine::new_unchecked(x, y);
+        if !p.is_on_curve() {
+            return Err("EIP-2537 point is not on the curve".into());
+        }
+        if !p.is_in_correct_subgroup_assuming_on_curve() {
+            return Err("EIP-2537 point is not in the prime order subgroup".into());
+        }
+        Ok(Eip2537(p))
+    }
+
+    fn encoded_fixed_size() -> Option<usize> {
+        Some(point_len::<C>())
+    }
+}
+
+impl<C: sw::SWCurveConfig> MaxEncodedLen for Eip2537<sw::Affine<C>>
+where
+    C::BaseField: Eip2537BaseField,
+{
+    #[inline]
+    fn max_encoded_len() -> usize {
+        point_len::<C>()
+    }
+}
+
+impl<C: sw::SWCurveConfig> TypeInfo for Eip2537<sw::Affine<C>>
+where
+    C::BaseField: Eip2537BaseField,
+{
+    type Identity = Self;
+
+    fn type_info() -> scale_info::Type {
+        type_info_array(point_len::<C>())
+    }
+}
+
+// Scalars //
+//
+// BLS12 scalar fields all fit into 4 limbs, and thus 32 bytes.
+
+impl<P: FpConfig<4>> EncodeLike for Eip2537<Fp<P, 4>> {}
+
+impl<P: FpConfig<4>> Encode for Eip2537<Fp<P, 4>> {
+    fn size_hint(&self) -> usize {
+        SCALAR_WIDTH
+    }
+
+    fn encode_to<O: Output + ?Sized>(&self, dest: &mut O) {
+        let mut buf = [0u8; SCALAR_WIDTH];
+        fp_to_be_bytes(&self.0, &mut buf);
+        dest.write(&buf);
+    }
+
+    fn encoded_size(&self) -> usize {
+        SCALAR_WIDTH
+    }
+}
+
+impl<P: FpConfig<4>> Decode for Eip2537<Fp<P, 4>> {
+    fn decode<I: Input>(input: &mut I) -> Result<Self, scale::Error> {
+        let mut buf = [0u8; SCALAR_WIDTH];
+        input.read(&mut buf)?;
+        fp_from_be_bytes(&buf)
+            .map(Eip2537)
+            .ok_or_else(|| "EIP-2537 scalar is not reduced".into())
+    }
+
+    fn encoded_fixed_size() -> Option<usize> {
+        Some(SCALAR_WIDTH)
+    }
+}
+
+impl<P: FpConfig<4>> MaxEncodedLen for Eip2537<Fp<P, 4>> {
+    #[inline]
+    fn max_encoded_len() -> usize {
+        SCALAR_WIDTH
+    }
+}
+
+impl<P: FpConfig<4>> TypeInfo for Eip2537<Fp<P, 4>> {
+    type Identity = Self;
+
+    fn type_info() -> scale_info::Type {
+        type_info_array(SCALAR_WIDTH)
+    }
+}
@@ -0,0 +1,135 @@
+//! `ArkScaleMaxEncodedLen` for pairing outputs and prepared pairing
+//! inputs, derived from the Miller loop parameters of each curve model,
+//! so that host calls taking them can be given weights.
+//!
+//! We bound prepared inputs by their non-identity form, because the
+//! identity prepares to empty coefficient vectors.
+
+use ark_ec::{
+    models::{bls12, bn, bw6, mnt4, mnt6},
+    pairing::{Pairing, PairingOutput},
+    CurveConfig,
+};
+use ark_ff::{BitIteratorBE, Field};
+use ark_serialize::Compress;
+
+use crate::ArkScaleMaxEncodedLen;
+
+/// Length of arkworks' `Vec` length prefix
+const LL: usize = 8;
+
+/// Length of arkworks' `bool`
+const BOOL: usize = 1;
+
+fn field_len<F: Field>() -> usize {
+    F::zero().serialized_size(Compress::No)
+}
+
+/// Doublings and additions in a Miller loop over the big-endian bits
+/// of `x`, skipping the leading bit, as in `bls12` and `bw6`.
+fn bits_loop_len(x: &[u64]) -> (usize, usize) {
+    let bits = BitIteratorBE::new(x).skip(1);
+    bits.fold((0, 0), |(d, a), b| (d + 1, a + b as usize))
+}
+
+/// Doublings and additions in a Miller loop over a signed digit
+/// representation, skipping one digit, as in `bn`, `bw6`, and `mnt`.
+fn naf_loop_len(naf: &[i8]) -> (usize, usize) {
+    let digits = naf.iter().skip(1);
+    digits.fold((0, 0), |(d, a), b| (d + 1, a + (*b != 0) as usize))
+}
+
+impl<P: Pairing> ArkScaleMaxEncodedLen for PairingOutput<P> {
+    #[inline]
+    fn max_encoded_len(_: Compress) -> usize {
+        field_len::<P::TargetField>()
+    }
+}
+
+// BLS12 //
+
+impl<P: bls12::Bls12Config> ArkScaleMaxEncodedLen for bls12::G1Prepared<P> {
+    #[inline]
+    fn max_encoded_len(compress: Compress) -> usize {
+        <P::G1Config as ark_ec::short_weierstrass::SWCurveConfig>::serialized_size(compress)
+    }
+}
+
+impl<P: bls12::Bls12Config> ArkScaleMaxEncodedLen for bls12::G2Prepared<P> {
+    fn max_encoded_len(_: Compress) -> usize {
+        let (d, a) = bits_loop_len(P::X);
+        LL + (d + a) * 3 * field_len::<<P::G2Config as CurveConfig>::BaseField>() + BOOL
+    }
+}
+
+// BN //
+
+impl<P: bn::BnConfig> ArkScaleMaxEncodedLen for bn::G1Prepared<P> {
+    #[inline]
+    fn max_encoded_len(compress: Compress) -> usize {
+        <P::G1Config as ark_ec::short_weierstrass::SWCurveConfig>::serialized_size(compress)
+    }
+}
+
+impl<P: bn::BnConfig> ArkScaleMaxEncodedLen for bn::G2Prepared<P> {
+    fn max_encoded_len(_: Compress) -> usize {
+        // The loop runs over ATE_LOOP_COUNT in reverse, skipping its
+        // last digit, and then adds two Frobenius twisted points.
+        let mut naf = P::ATE_LOOP_COUNT.to_vec();
+        naf.reverse();
+        let (d, a) = naf_loop_len(&naf);
+        LL + (d + a + 2) * 3 * field_len::<<P::G2Config as CurveConfig>::BaseField>() + BOOL
+    }
+}
+
+// BW6 //
+
+impl<P: bw6::BW6Config> ArkScaleMaxEncodedLen for bw6::G1Prepared<P> {
+    #[inline]
+    fn max_encoded_len(compress: Compress) -> usize {
+        <P::G1Config as ark_ec::short_weierstrass::SWCurveConfig>::serialized_size(compress)
+    }
+}
+
+impl<P: bw6::BW6Config> ArkScaleMaxEncodedLen for bw6::G2Prepared<P> {
+    fn max_encoded_len(_: Compress) -> usize {
+        let (d1, a1) = bits_loop_len(P::ATE_LOOP_COUNT_1);
+        let mut naf = P::ATE_LOOP_COUNT_2.to_vec();
+        naf.reverse();
+        let (d2, a2) = naf_loop_len(&naf);
+        let coeff = 3 * field_len::<P::Fp>();
+        LL + (d1 + a1 + 1) * coeff + LL + (d2 + a2) * coeff + BOOL
+    }
+}
+
+// MNT4 and MNT6 //
+
+impl<P: mnt4::MNT4Config> ArkScaleMaxEncodedLen for mnt4::G1Prepared<P> {
+    fn max_encoded_len(_: Compress) -> usize {
+        2 * field_len::<P::Fp>() + 2 * field_len::<ark_ff::Fp2<P::Fp2Config>>()
+    }
+}
+
+impl<P: mnt4::MNT4Config> ArkScaleMaxEncodedLen for mnt4::G2Prepared<P> {
+    fn max_encoded_len(_: Compress) -> usize {
+        let (d, a) = naf_loop_len(P::ATE_LOOP_COUNT);
+        let a = a + P::ATE_IS_LOOP_COUNT_NEG as usize;
+        let f = field_len::<ark_ff::Fp2<P::Fp2Config>>();
+        4 * f + LL + d * 4 * f + LL + a * 2 * f
+    }
+}
+
+impl<P: mnt6::MNT6Config> ArkScaleMaxEncodedLen for mnt6::G1Prepared<P> {
+    fn max_encoded_len(_: Compress) -> usize {
+        2 * field_len::<P::Fp>() + 2 * field_len::<ark_ff::Fp3<P::Fp3Config>>()
+    }
+}
+
+impl<P: mnt6::MNT6Config> ArkScaleMaxEncodedLen for mnt6::G2Prepared<P> {
+    fn max_encoded_len(_: Compress) -> usize {
+        let (d, a) = naf_loop_len(P::ATE_LOOP_COUNT);
+        let a = a + P::ATE_IS_LOOP_COUNT_NEG as usize;
+        let f = field_len::<ark_ff::Fp3<P::Fp3Config>>();
+        4 * f + LL + d * 4 * f + LL + a * 2 * f
+    }
+}
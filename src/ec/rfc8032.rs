@@ -0,0 +1,176 @@
+//! # RFC 8032 encoding for twisted Edwards curves
+//!
+//! [RFC 8032](https://www.rfc-editor.org/rfc/rfc8032#section-5.1.2)
+//! encodes Ed25519 points as the little-endian `y` coordinate, with the
+//! parity of `x` in the otherwise unused top bit of the last byte.
+//! Zcash' Jubjub and most Bandersnatch implementations follow the same
+//! convention, so we support it for any twisted Edwards curve whose
+//! base field is a prime field leaving the top bit spare.
+//!
+//! Arkworks instead writes a flag for whether `x` exceeds `-x`, not its
+//! parity, so `ArkScale<EdwardsAffine>` differs from RFC 8032.
+
+use ark_std::{marker::PhantomData, vec::Vec, Zero};
+
+use ark_ec::{models::twisted_edwards as te, AffineRepr, CurveGroup};
+use ark_ff::{BigInteger, PrimeField};
+
+use crate::{
+    ff::fp_be_len,
+    scale::{self, Decode, Encode, EncodeLike, Input, MaxEncodedLen, Output},
+};
+use scale_info::TypeInfo;
+
+/// Checks applied when decoding `Rfc8032` points, morally a pair of
+/// booleans, like `Usage`.
+pub type EdwardsChecks = u8;
+
+/// Reject `y` coordinates not less than the modulus, as well as
+/// `x = 0` with the sign bit set.  RFC 8032 demands this check, but
+/// ed25519-dalek and others omit it.
+pub const CANONICAL_Y: EdwardsChecks = 1;
+
+/// Reject points whose order divides the cofactor, as ed25519-dalek's
+/// `verify_strict` does for public keys.
+pub const REJECT_SMALL_ORDER: EdwardsChecks = 2;
+
+/// Decoding exactly as specified in RFC 8032
+pub const RFC8032: EdwardsChecks = CANONICAL_Y;
+
+/// Decoding suitable for public keys in strict verification
+pub const STRICT: EdwardsChecks = CANONICAL_Y | REJECT_SMALL_ORDER;
+
+const SIGN_BIT: u8 = 0x80;
+
+/// Twisted Edwards point wrapped for serialization by SCALE in
+/// RFC 8032 layout, subject to checks `K` when decoding.
+///
+/// We never check subgroup membership beyond `REJECT_SMALL_ORDER`,
+/// so decoded points may have a torsion component, as in Ed25519.
+#[derive(Clone, Eq, PartialEq, Debug)]
+#[repr(transparent)]
+pub struct Rfc8032<T, const K: EdwardsChecks = RFC8032>(pub T);
+
+impl<T, const K: EdwardsChecks> From<T> for Rfc8032<T, K> {
+    fn from(t: T) -> Rfc8032<T, K> {
+        Rfc8032(t)
+    }
+}
+
+/// Length of `Rfc8032<te::Affine<C>>`, checked at compile time
+struct PointLen<C>(PhantomData<C>);
+
+impl<C: te::TECurveConfig> PointLen<C>
+where
+    C::BaseField: PrimeField,
+{
+    const LEN: usize = {
+        assert!(
+            (C::BaseField::MODULUS_BIT_SIZE as usize) < 8 * fp_be_len::<C::BaseField>(),
+            "RFC 8032 encoding needs a spare top bit in the base field."
+        );
+        fp_be_len::<C::BaseField>()
+    };
+}
+
+fn point_len<C: te::TECurveConfig>() -> usize
+where
+    C::BaseField: PrimeField,
+{
+    PointLen::<C>::LEN
+}
+
+fn is_odd<F: PrimeField>(f: &F) -> bool {
+    f.into_bigint().is_odd()
+}
+
+impl<C: te::TECurveConfig, const K: EdwardsChecks> EncodeLike for Rfc8032<te::Affine<C>, K> where
+    C::BaseField: PrimeField
+{
+}
+
+impl<C: te::TECurveConfig, const K: EdwardsChecks> Encode for Rfc8032<te::Affine<C>, K>
+where
+    C::BaseField: PrimeField,
+{
+    fn size_hint(&self) -> usize {
+        point_len::<C>()
+    }
+
+    fn encode_to<O: Output + ?Sized>(&self, dest: &mut O) {
+        let mut bytes = self.0.y.into_bigint().to_bytes_le();
+        bytes.truncate(point_len::<C>());
+        if is_odd(&self.0.x) {
+            *bytes.last_mut().unwrap() |= SIGN_BIT;
+        }
+        dest.write(&bytes);
+    }
+
+    fn encoded_size(&self) -> usize {
+        point_len::<C>()
+    }
+}
+
+impl<C: te::TECurveConfig, const K: EdwardsChecks> Decode for Rfc8032<te::Affine<C>, K>
+where
+    C::BaseField: PrimeField,
+{
+    fn decode<I: Input>(input: &mut I) -> Result<Self, scale::Error> {
+        let mut bytes: Vec<u8> = ark_std::vec![0u8; point_len::<C>()];
+        input.read(&mut bytes)?;
+        let last = bytes.last_mut().unwrap();
+        let sign = *last & SIGN_BIT != 0;
+        *last &= !SIGN_BIT;
+
+        let y = C::BaseField::from_le_bytes_mod_order(&bytes);
+        let (x0, x1) = te::Affine::<C>::get_xs_from_y_unchecked(y)
+            .ok_or("RFC 8032 point is not on the curve")?;
+        if K & CANONICAL_Y != 0 {
+            let mut canonical = y.into_bigint().to_bytes_le();
+            canonical.truncate(bytes.len());
+            if canonical != bytes {
+                return Err("RFC 8032 y coordinate is not canonical".into());
+            }
+            if sign && x0.is_zero() {
+                return Err("RFC 8032 point has x = 0 with the sign bit set".into());
+            }
+        }
+        let x = if is_odd(&x0) == sign { x0 } else { x1 };
+        let p = te::Affine::new_unchecked(x, y);
+        if K & REJECT_SMALL_ORDER != 0 && p.mul_by_cofactor_to_group().into_affine().is_zero() {
+            return Err("RFC 8032 point has small order".into());
+        }
+        Ok(Rfc8032(p))
+    }
+
+    fn encoded_fixed_size() -> Option<usize> {
+        Some(point_len::<C>())
+    }
+}
+
+impl<C: te::TECurveConfig, const K: EdwardsChecks> MaxEncodedLen for Rfc8032<te::Affine<C>, K>
+where
+    C::BaseField: PrimeField,
+{
+    #[inline]
+    fn max_encoded_len() -> usize {
+        point_len::<C>()
+    }
+}
+
+impl<C: te::TECurveConfig, const K: EdwardsChecks> TypeInfo for Rfc8032<te::Affine<C>, K>
+where
+    C::BaseField: PrimeField,
+{
+    type Identity = Self;
+
+    fn type_info() -> scale_info::Type {
+        let path = scale_info::Path::new("Rfc8032", module_path!());
+        let array_type_def = scale_info::TypeDefArray {
+            len: point_len::<C>() as u32,
+            type_param: scale_info::MetaType::new::<u8>(),
+        };
+        let type_def = scale_info::TypeDef::Array(array_type_def);
+        scale_info::Type { path, type_params: Vec::new(), type_def, docs: Vec::new() }
+    }
+}
@@ -0,0 +1,240 @@
+//! # Ristretto encoding for twisted Edwards curves
+//!
+//! [Ristretto](https://www.rfc-editor.org/rfc/rfc9496) builds a prime
+//! order group with canonical encodings from a cofactor 8 twisted
+//! Edwards curve with `a = -1` over a prime field with `p = 5 mod 8`,
+//! in practice only Ed25519, by encoding elements of `2E / E[4]`
+//! instead of curve points.  `RistrettoScale<te::Affine<C>>` requires
+//! `C: RistrettoConfig`, which the `ristretto` feature provides for
+//! `ark_ed25519`, making it interoperable with curve25519-dalek's
+//! `RistrettoPoint`.
+//!
+//! We hold some representative curve point, which may differ from the
+//! originally encoded point by a 4-torsion point, so `PartialEq` for
+//! `RistrettoScale` compares on the quotient group, not coordinates.
+//! Anyone who extracts the inner point must take similar care, so
+//! for example clearing the cofactor yields a canonical representative.
+//!
+//! We do not provide Decaf for cofactor 4 curves, nor Ristretto for
+//! Jubjub or Bandersnatch.  Bandersnatch in twisted Edwards form has
+//! `a = -5`, not `a = -1`, and Jubjub has `p = 1 mod 8`, so neither
+//! satisfies the preconditions of the RFC 9496 formulas we implement,
+//! and neither has standardized encodings or test vectors.
+//! Arkworks is not constant time, and neither is this module.
+
+use ark_std::{vec::Vec, Zero, One};
+
+use ark_ec::models::twisted_edwards as te;
+use ark_ff::{BigInteger, Field, PrimeField};
+
+use crate::{
+    ff::fp_be_len,
+    scale::{self, Decode, Encode, EncodeLike, Input, MaxEncodedLen, Output},
+};
+use scale_info::TypeInfo;
+
+/// Cofactor 8 twisted Edwards curves with `a = -1` over a prime field
+/// with `p = 5 mod 8`, along with the constants from RFC 9496 section
+/// 4.1, which Ristretto requires.
+///
+/// Implementations for other curves panic or misbehave.
+pub trait RistrettoConfig: te::TECurveConfig {
+    /// Non-negative square root of `-1`
+    const SQRT_M1: Self::BaseField;
+    /// Non-negative `1 / sqrt(a - d)`
+    const INVSQRT_A_MINUS_D: Self::BaseField;
+}
+
+#[cfg(feature = "ristretto")]
+impl RistrettoConfig for ark_ed25519::EdwardsConfig {
+    const SQRT_M1: ark_ed25519::Fq =
+        ark_ff::MontFp!("19681161376707505956807079304988542015446066515923890162744021073123829784752");
+    const INVSQRT_A_MINUS_D: ark_ed25519::Fq =
+        ark_ff::MontFp!("54469307008909316920995813868745141605393597292927456921205312896311721017578");
+}
+
+/// Twisted Edwards point wrapped for serialization by SCALE as an
+/// element of the Ristretto group.
+///
+/// We encode only points in `2E`, like those which `RistrettoScale`
+/// decodes, points of prime order, and their sums.  We never check
+/// this when encoding, so encoding any other point, like one with an
+/// 8-torsion component, silently yields the encoding of some unrelated
+/// element, or an invalid encoding.
+#[derive(Clone, Copy, Debug)]
+#[repr(transparent)]
+pub struct RistrettoScale<T>(pub T);
+
+impl<T> From<T> for RistrettoScale<T> {
+    fn from(t: T) -> RistrettoScale<T> {
+        RistrettoScale(t)
+    }
+}
+
+fn is_negative<F: PrimeField>(f: &F) -> bool {
+    f.into_bigint().is_odd()
+}
+
+fn abs<F: PrimeField>(f: F) -> F {
+    if is_negative(&f) { -f } else { f }
+}
+
+/// `SQRT_RATIO_M1` from RFC 9496 section 4.2
+fn sqrt_ratio_m1<C: RistrettoConfig>(u: C::BaseField, v: C::BaseField) -> (bool, C::BaseField)
+where
+    C::BaseField: PrimeField,
+{
+    if u.is_zero() {
+        return (true, C::BaseField::zero());
+    }
+    let Some(v_inv) = v.inverse() else {
+        return (false, C::BaseField::zero());
+    };
+    let r = u * v_inv;
+    match r.sqrt() {
+        Some(s) => (true, abs(s)),
+        // As p = 5 mod 8, sqrt(-1) is not square, so sqrt(-1) * r is.
+        None => (false, abs((C::SQRT_M1 * r).sqrt().expect("RistrettoConfig requires p = 5 mod 8."))),
+    }
+}
+
+fn point_len<C: RistrettoConfig>() -> usize
+where
+    C::BaseField: PrimeField,
+{
+    fp_be_len::<C::BaseField>()
+}
+
+/// Ristretto encoding of `p` as `s`, from RFC 9496 section 4.3.2.
+pub fn ristretto_encode<C: RistrettoConfig>(p: &te::Affine<C>) -> C::BaseField
+where
+    C::BaseField: PrimeField,
+{
+    let one = C::BaseField::one();
+    let (x0, y0) = (p.x, p.y);
+    let t0 = x0 * y0;
+    let u1 = (one + y0) * (one - y0);
+    let u2 = x0 * y0;
+    let (_, invsqrt) = sqrt_ratio_m1::<C>(one, u1 * u2.square());
+    let den1 = invsqrt * u1;
+    let den2 = invsqrt * u2;
+    let z_inv = den1 * den2 * t0;
+    let rotate = is_negative(&(t0 * z_inv));
+    let (x, mut y, den_inv) = if rotate {
+        (y0 * C::SQRT_M1, x0 * C::SQRT_M1, den1 * C::INVSQRT_A_MINUS_D)
+    } else {
+        (x0, y0, den2)
+    };
+    if is_negative(&(x * z_inv)) {
+        y = -y;
+    }
+    abs(den_inv * (one - y))
+}
+
+/// Ristretto decoding of `s`, from RFC 9496 section 4.3.1.
+///
+/// Returns some representative of the encoded coset.
+pub fn ristretto_decode<C: RistrettoConfig>(s: C::BaseField) -> Option<te::Affine<C>>
+where
+    C::BaseField: PrimeField,
+{
+    if is_negative(&s) {
+        return None;
+    }
+    let one = C::BaseField::one();
+    let ss = s.square();
+    let u1 = one - ss;
+    let u2 = one + ss;
+    let u2_sqr = u2.square();
+    let v = -(C::COEFF_D * u1.square()) - u2_sqr;
+    let (was_square, invsqrt) = sqrt_ratio_m1::<C>(one, v * u2_sqr);
+    let den_x = invsqrt * u2;
+    let den_y = invsqrt * den_x * v;
+    let x = abs((s + s) * den_x);
+    let y = u1 * den_y;
+    if !was_square || is_negative(&(x * y)) || y.is_zero() {
+        return None;
+    }
+    Some(te::Affine::new_unchecked(x, y))
+}
+
+impl<C: RistrettoConfig> PartialEq for RistrettoScale<te::Affine<C>> {
+    /// Equality in `2E / E[4]`, from RFC 9496 section 4.3.3.
+    fn eq(&self, other: &Self) -> bool {
+        let (p, q) = (&self.0, &other.0);
+        p.x * q.y == p.y * q.x || p.y * q.y == p.x * q.x
+    }
+}
+
+impl<C: RistrettoConfig> Eq for RistrettoScale<te::Affine<C>> {}
+
+impl<C: RistrettoConfig> EncodeLike for RistrettoScale<te::Affine<C>> where C::BaseField: PrimeField {}
+
+impl<C: RistrettoConfig> Encode for RistrettoScale<te::Affine<C>>
+where
+    C::BaseField: PrimeField,
+{
+    fn size_hint(&self) -> usize {
+        point_len::<C>()
+    }
+
+    fn encode_to<O: Output + ?Sized>(&self, dest: &mut O) {
+        let mut bytes = ristretto_encode(&self.0).into_bigint().to_bytes_le();
+        bytes.truncate(point_len::<C>());
+        dest.write(&bytes);
+    }
+
+    fn encoded_size(&self) -> usize {
+        point_len::<C>()
+    }
+}
+
+impl<C: RistrettoConfig> Decode for RistrettoScale<te::Affine<C>>
+where
+    C::BaseField: PrimeField,
+{
+    fn decode<I: Input>(input: &mut I) -> Result<Self, scale::Error> {
+        let mut bytes: Vec<u8> = ark_std::vec![0u8; point_len::<C>()];
+        input.read(&mut bytes)?;
+        let s = C::BaseField::from_le_bytes_mod_order(&bytes);
+        let mut canonical = s.into_bigint().to_bytes_le();
+        canonical.truncate(bytes.len());
+        if canonical != bytes {
+            return Err("Ristretto encoding is not canonical".into());
+        }
+        ristretto_decode::<C>(s)
+            .map(RistrettoScale)
+            .ok_or_else(|| "Ristretto encoding is invalid".into())
+    }
+
+    fn encoded_fixed_size() -> Option<usize> {
+        Some(point_len::<C>())
+    }
+}
+
+impl<C: RistrettoConfig> MaxEncodedLen for RistrettoScale<te::Affine<C>>
+where
+    C::BaseField: PrimeField,
+{
+    #[inline]
+    fn max_encoded_len() -> usize {
+        point_len::<C>()
+    }
+}
+
+impl<C: RistrettoConfig> TypeInfo for RistrettoScale<te::Affine<C>>
+where
+    C::BaseField: PrimeField,
+{
+    type Identity = Self;
+
+    fn type_info() -> scale_info::Type {
+        let path = scale_info::Path::new("RistrettoScale", module_path!());
+        let array_type_def = scale_info::TypeDefArray {
+            len: point_len::<C>() as u32,
+            type_param: scale_info::MetaType::new::<u8>(),
+        };
+        let type_def = scale_info::TypeDef::Array(array_type_def);
+        scale_info::Type { path, type_params: Vec::new(), type_def, docs: Vec::new() }
+    }
+}
@@ -0,0 +1,191 @@
+//! # SEC1 encoding for short Weierstrass curves
+//!
+//! [SEC1](https://www.secg.org/sec1-v2.pdf) section 2.3.3 encodes points
+//! as `0x02 || x` or `0x03 || x` when compressed, depending upon the
+//! parity of `y`, and as `0x04 || x || y` when uncompressed, with
+//! big-endian coordinates.  The identity becomes the single byte `0x00`.
+//! Ethereum and Bitcoin public keys on secp256k1, as well as most
+//! P-256 libraries, use this encoding.
+
+use ark_std::{marker::PhantomData, vec, vec::Vec};
+
+use ark_ec::{models::short_weierstrass as sw, AffineRepr};
+use ark_ff::{BigInteger, PrimeField};
+use ark_serialize::{Compress, Validate};
+
+use crate::{
+    ff::{fp_be_len, fp_from_be_bytes, fp_to_be_bytes},
+    is_compressed, is_validated,
+    scale::{self, Decode, Encode, EncodeLike, Input, MaxEncodedLen, Output},
+    Usage, WIRE,
+};
+use scale_info::TypeInfo;
+
+const IDENTITY: u8 = 0x00;
+const EVEN: u8 = 0x02;
+const ODD: u8 = 0x03;
+const UNCOMPRESSED: u8 = 0x04;
+
+/// Short Weierstrass point wrapped for serialization by SCALE in
+/// SEC1 layout.
+///
+/// We encode compressed or uncompressed according to `U`, and decode
+/// only that form, so an uncompressed `Sec1` rejects compressed keys.
+/// If `U` validates then we check the curve equation and subgroup
+/// membership, which compressed points satisfy automatically whenever
+/// the cofactor is one.
+#[derive(Clone, Eq, PartialEq, Debug)]
+#[repr(transparent)]
+pub struct Sec1<T, const U: Usage = WIRE>(pub T);
+
+impl<T, const U: Usage> From<T> for Sec1<T, U> {
+    fn from(t: T) -> Sec1<T, U> {
+        Sec1(t)
+    }
+}
+
+fn point_len<C: sw::SWCurveConfig>(compress: Compress) -> usize
+where
+    C::BaseField: PrimeField,
+{
+    let l = fp_be_len::<C::BaseField>();
+    match compress {
+        Compress::Yes => 1 + l,
+        Compress::No => 1 + 2 * l,
+    }
+}
+
+impl<C: sw::SWCurveConfig, const U: Usage> EncodeLike for Sec1<sw::Affine<C>, U> where C::BaseField: PrimeField {}
+
+impl<C: sw::SWCurveConfig, const U: Usage> Encode for Sec1<sw::Affine<C>, U>
+where
+    C::BaseField: PrimeField,
+{
+    fn size_hint(&self) -> usize {
+        self.encoded_size()
+    }
+
+    fn encode_to<O: Output + ?Sized>(&self, dest: &mut O) {
+        let Some((x, y)) = self.0.xy() else {
+            dest.push_byte(IDENTITY);
+            return;
+        };
+        let l = fp_be_len::<C::BaseField>();
+        let mut bytes = vec![0u8; point_len::<C>(is_compressed(U))];
+        fp_to_be_bytes(&x, &mut bytes[1..1 + l]);
+        match is_compressed(U) {
+            Compress::Yes => {
+                bytes[0] = if y.into_bigint().is_odd() { ODD } else { EVEN };
+            }
+            Compress::No => {
+                bytes[0] = UNCOMPRESSED;
+                fp_to_be_bytes(&y, &mut bytes[1 + l..]);
+            }
+        }
+        dest.write(&bytes);
+    }
+
+    fn encoded_size(&self) -> usize {
+        if self.0.is_zero() {
+            1
+        } else {
+            point_len::<C>(is_compressed(U))
+        }
+    }
+}
+
+impl<C: sw::SWCurveConfig, const U: Usage> Decode for Sec1<sw::Affine<C>, U>
+where
+    C::BaseField: PrimeField,
+{
+    fn decode<I: Input>(input: &mut I) -> Result<Self, scale::Error> {
+        let tag = input.read_byte()?;
+        if tag == IDENTITY {
+            return Ok(Sec1(sw::Affine::identity()));
+        }
+        let l = fp_be_len::<C::BaseField>();
+        let mut bytes = vec![0u8; point_len::<C>(is_compressed(U)) - 1];
+        input.read(&mut bytes)?;
+        let err = || scale::Error::from("SEC1 coordinate is not canonical");
+        let x = fp_from_be_bytes::<C::BaseField>(&bytes[..l]).ok_or_else(err)?;
+        let p = match (is_compressed(U), tag) {
+            (Compress::Yes, EVEN | ODD) => {
+                let (a, b) = sw::Affine::<C>::get_ys_from_x_unchecked(x)
+                    .ok_or("SEC1 point is not on the curve")?;
+                let y = if a.into_bigint().is_odd() == (tag == ODD) { a } else { b };
+                sw::Affine::new_unchecked(x, y)
+            }
+            (Compress::No, UNCOMPRESSED) => {
+                let y = fp_from_be_bytes::<C::BaseField>(&bytes[l..]).ok_or_else(err)?;
+                let p = sw::Affine::new_unchecked(x, y);
+                if is_validated(U) == Validate::Yes && !p.is_on_curve() {
+                    return Err("SEC1 point is not on the curve".into());
+                }
+                p
+            }
+            _ => return Err("SEC1 point has an unexpected tag".into()),
+        };
+        if is_validated(U) == Validate::Yes && !p.is_in_correct_subgroup_assuming_on_curve() {
+            return Err("SEC1 point is not in the prime order subgroup".into());
+        }
+        Ok(Sec1(p))
+    }
+}
+
+impl<C: sw::SWCurveConfig, const U: Usage> MaxEncodedLen for Sec1<sw::Affine<C>, U>
+where
+    C::BaseField: PrimeField,
+{
+    #[inline]
+    fn max_encoded_len() -> usize {
+        point_len::<C>(is_compressed(U))
+    }
+}
+
+/// Big-endian coordinates described for `TypeInfo`
+struct Coordinates<F, const K: usize>(PhantomData<F>);
+
+impl<F: PrimeField, const K: usize> TypeInfo for Coordinates<F, K> {
+    type Identity = Self;
+
+    fn type_info() -> scale_info::Type {
+        let path = scale_info::Path::new("Coordinates", module_path!());
+        let array_type_def = scale_info::TypeDefArray {
+            len: (K * fp_be_len::<F>()) as u32,
+            type_param: scale_info::MetaType::new::<u8>(),
+        };
+        let type_def = scale_info::TypeDef::Array(array_type_def);
+        scale_info::Type { path, type_params: Vec::new(), type_def, docs: Vec::new() }
+    }
+}
+
+/// SEC1 tags coincide with SCALE enum discriminants.
+impl<C: sw::SWCurveConfig, const U: Usage> TypeInfo for Sec1<sw::Affine<C>, U>
+where
+    C::BaseField: PrimeField,
+{
+    type Identity = Self;
+
+    fn type_info() -> scale_info::Type {
+        use scale_info::build::{Fields, Variants};
+        let variants = Variants::new().variant_unit("Identity", IDENTITY);
+        let variants = match is_compressed(U) {
+            Compress::Yes => variants
+                .variant("Even", |v| {
+                    v.index(EVEN)
+                        .fields(Fields::unnamed().field(|f| f.ty::<Coordinates<C::BaseField, 1>>()))
+                })
+                .variant("Odd", |v| {
+                    v.index(ODD)
+                        .fields(Fields::unnamed().field(|f| f.ty::<Coordinates<C::BaseField, 1>>()))
+                }),
+            Compress::No => variants.variant("Uncompressed", |v| {
+                v.index(UNCOMPRESSED)
+                    .fields(Fields::unnamed().field(|f| f.ty::<Coordinates<C::BaseField, 2>>()))
+            }),
+        };
+        scale_info::Type::builder()
+            .path(scale_info::Path::new("Sec1", module_path!()))
+            .variant(variants)
+    }
+}
@@ -0,0 +1,308 @@
+//! # Torus compression for pairing target group elements
+//!
+//! Pairing outputs live in the cyclotomic subgroup of `Fp12 = Fp6[w]`,
+//! so they have norm one over `Fp6`, which places them on the algebraic
+//! torus `T2(Fp6)`.  `Torus` compresses `g = g0 + g1 w` to
+//! `c = (1 + g0) / g1` in `Fp6`, which halves the size, and decompresses
+//! by `g = (c + w) / (c - w)`.  We flag the identity, where `c` would be
+//! infinite, so every norm one element including `-1` encodes distinctly.
+//!
+//! Over BLS12 and BN curves, pairing outputs have norm one over `Fp4`
+//! too, which places them on the smaller torus `T6(Fp2)`.  There, with
+//! `Fp6 = Fp2[v]` and `w^2 = v`, writing `c = x + y v + z v^2` the norm
+//! condition becomes `x y = xi z^2 + 1/3` where `v^3 = xi`.  As `xi`
+//! is not square in `Fp2`, neither side vanishes, so `Torus6` encodes
+//! `(z, y)` and recovers `x`, which compresses to one third.
+//!
+//! We assume the wrapped element lies on the relevant torus, so encoding
+//! anything else loses information.
+
+use ark_std::{vec::Vec, One, Zero};
+
+use ark_ec::pairing::{Pairing, PairingOutput};
+use ark_ff::{fields::models::*, Field};
+use ark_serialize::{
+    CanonicalDeserialize, CanonicalDeserializeWithFlags, CanonicalSerialize, CanonicalSerializeWithFlags,
+    Compress, Flags, Validate,
+};
+
+use crate::{
+    is_validated, rw::*, OOPS, Usage, WIRE,
+    scale::{self, Decode, Encode, EncodeLike, Input, MaxEncodedLen, Output},
+};
+use scale_info::TypeInfo;
+
+/// Pairing output wrapped for serialization by SCALE in its torus
+/// compressed form, half the size of `ArkScale<PairingOutput<P>>`.
+///
+/// We ignore the `Compress` flag in `U` but if `U` validates then
+/// we check membership in the prime order target group.
+#[derive(Clone, Eq, PartialEq, Debug)]
+#[repr(transparent)]
+pub struct Torus<T, const U: Usage = WIRE>(pub T);
+
+impl<T, const U: Usage> From<T> for Torus<T, U> {
+    fn from(t: T) -> Torus<T, U> {
+        Torus(t)
+    }
+}
+
+/// BLS12 or BN pairing output wrapped for serialization by SCALE in its
+/// `T6` torus compressed form, one third the size of
+/// `ArkScale<PairingOutput<P>>`.
+///
+/// We ignore the `Compress` flag in `U` but if `U` validates then
+/// we check membership in the prime order target group.
+#[derive(Clone, Eq, PartialEq, Debug)]
+#[repr(transparent)]
+pub struct Torus6<T, const U: Usage = WIRE>(pub T);
+
+impl<T, const U: Usage> From<T> for Torus6<T, U> {
+    fn from(t: T) -> Torus6<T, U> {
+        Torus6(t)
+    }
+}
+
+/// Flag in the final field element of a torus encoding
+#[derive(Clone, Copy, Default, PartialEq, Eq, Debug)]
+enum TorusFlags {
+    #[default]
+    Finite,
+    Identity,
+}
+
+impl Flags for TorusFlags {
+    const BIT_SIZE: usize = 1;
+
+    #[inline]
+    fn u8_bitmask(&self) -> u8 {
+        match self {
+            TorusFlags::Finite => 0,
+            TorusFlags::Identity => 1 << 7,
+        }
+    }
+
+    #[inline]
+    fn from_u8(value: u8) -> Option<Self> {
+        Some(if value >> 7 == 1 { TorusFlags::Identity } else { TorusFlags::Finite })
+    }
+}
+
+/// Torus compression of `g` in the norm one subgroup of `QuadExtField<Q>`,
+/// or `None` for the identity.
+pub fn t2_compress<Q: QuadExtConfig>(g: &QuadExtField<Q>) -> Option<Q::BaseField> {
+    match g.c1.inverse() {
+        Some(c1_inv) => Some((Q::BaseField::one() + g.c0) * c1_inv),
+        None if g.c0.is_one() => None,
+        // Only -1 remains, which the formula sends to zero.
+        None => Some(Q::BaseField::zero()),
+    }
+}
+
+/// Torus decompression of `c`, always yielding some norm one element
+/// other than the identity.
+pub fn t2_decompress<Q: QuadExtConfig>(c: &Q::BaseField) -> QuadExtField<Q> {
+    let cc = c.square();
+    // c^2 - v never vanishes, because v is a non-residue in the base field.
+    let d_inv = (cc - Q::NONRESIDUE).inverse().unwrap();
+    QuadExtField::new((cc + Q::NONRESIDUE) * d_inv, (*c + c) * d_inv)
+}
+
+type Fp2Of<C> = Fp2<<<C as Fp12Config>::Fp6Config as Fp6Config>::Fp2Config>;
+
+/// Torus `T6` compression of `g` in the cyclotomic subgroup of `Fp12<C>`,
+/// as `(z, y)` where `x + y v + z v^2` is its `T2` compression, or `None`
+/// for the identity.
+pub fn t6_compress<C: Fp12Config>(g: &Fp12<C>) -> Option<(Fp2Of<C>, Fp2Of<C>)> {
+    t2_compress(g).map(|c| (c.c2, c.c1))
+}
+
+/// Torus `T6` decompression of `(z, y)`, always yielding some element
+/// of `T6` other than the identity, or `None` if `y = 0`.
+pub fn t6_decompress<C: Fp12Config>(z: &Fp2Of<C>, y: &Fp2Of<C>) -> Option<Fp12<C>> {
+    // Pairing friendly fields never have characteristic 3.
+    let third = Fp2Of::<C>::from(3u64).inverse().unwrap();
+    let xy = <C::Fp6Config as Fp6Config>::NONRESIDUE * z.square() + third;
+    let x = xy * y.inverse()?;
+    Some(t2_decompress(&Fp6::new(x, *y, *z)))
+}
+
+fn compressed_len<Q: QuadExtConfig>() -> usize {
+    Q::BaseField::zero().serialized_size_with_flags::<TorusFlags>()
+}
+
+fn compressed6_len<C: Fp12Config>() -> usize {
+    let zero = Fp2Of::<C>::zero();
+    zero.serialized_size(Compress::No) + zero.serialized_size_with_flags::<TorusFlags>()
+}
+
+impl<P, Q, const U: Usage> EncodeLike for Torus<PairingOutput<P>, U>
+where
+    P: Pairing<TargetField = QuadExtField<Q>>,
+    Q: QuadExtConfig,
+{
+}
+
+impl<P, Q, const U: Usage> Encode for Torus<PairingOutput<P>, U>
+where
+    P: Pairing<TargetField = QuadExtField<Q>>,
+    Q: QuadExtConfig,
+{
+    fn size_hint(&self) -> usize {
+        compressed_len::<Q>()
+    }
+
+    fn encode_to<O: Output + ?Sized>(&self, dest: &mut O) {
+        let (c, flags) = match t2_compress(&self.0 .0) {
+            Some(c) => (c, TorusFlags::Finite),
+            None => (Q::BaseField::zero(), TorusFlags::Identity),
+        };
+        c.serialize_with_flags(OutputAsWrite(dest), flags).expect(OOPS);
+    }
+
+    fn encoded_size(&self) -> usize {
+        compressed_len::<Q>()
+    }
+}
+
+impl<P, Q, const U: Usage> Decode for Torus<PairingOutput<P>, U>
+where
+    P: Pairing<TargetField = QuadExtField<Q>>,
+    Q: QuadExtConfig,
+{
+    fn decode<I: Input>(input: &mut I) -> Result<Self, scale::Error> {
+        let (c, flags) = <Q::BaseField as CanonicalDeserializeWithFlags>::deserialize_with_flags(InputAsRead(input))
+            .map_err(ark_error_to_scale_error)?;
+        let g = match flags {
+            TorusFlags::Finite => t2_decompress::<Q>(&c),
+            TorusFlags::Identity if c.is_zero() => QuadExtField::one(),
+            TorusFlags::Identity => return Err("Torus compressed identity is not canonical".into()),
+        };
+        if is_validated(U) == Validate::Yes && !g.pow(P::ScalarField::characteristic()).is_one() {
+            return Err("Torus compressed element is not in the pairing target group".into());
+        }
+        Ok(Torus(PairingOutput(g)))
+    }
+
+    fn encoded_fixed_size() -> Option<usize> {
+        Some(compressed_len::<Q>())
+    }
+}
+
+impl<P, Q, const U: Usage> MaxEncodedLen for Torus<PairingOutput<P>, U>
+where
+    P: Pairing<TargetField = QuadExtField<Q>>,
+    Q: QuadExtConfig,
+{
+    #[inline]
+    fn max_encoded_len() -> usize {
+        compressed_len::<Q>()
+    }
+}
+
+impl<P, Q, const U: Usage> TypeInfo for Torus<PairingOutput<P>, U>
+where
+    P: Pairing<TargetField = QuadExtField<Q>>,
+    Q: QuadExtConfig,
+{
+    type Identity = Self;
+
+    fn type_info() -> scale_info::Type {
+        let path = scale_info::Path::new("Torus", module_path!());
+        let array_type_def = scale_info::TypeDefArray {
+            len: compressed_len::<Q>() as u32,
+            type_param: scale_info::MetaType::new::<u8>(),
+        };
+        let type_def = scale_info::TypeDef::Array(array_type_def);
+        scale_info::Type { path, type_params: Vec::new(), type_def, docs: Vec::new() }
+    }
+}
+
+impl<P, C, const U: Usage> EncodeLike for Torus6<PairingOutput<P>, U>
+where
+    P: Pairing<TargetField = Fp12<C>>,
+    C: Fp12Config,
+{
+}
+
+impl<P, C, const U: Usage> Encode for Torus6<PairingOutput<P>, U>
+where
+    P: Pairing<TargetField = Fp12<C>>,
+    C: Fp12Config,
+{
+    fn size_hint(&self) -> usize {
+        compressed6_len::<C>()
+    }
+
+    fn encode_to<O: Output + ?Sized>(&self, dest: &mut O) {
+        let ((z, y), flags) = match t6_compress(&self.0 .0) {
+            Some(zy) => (zy, TorusFlags::Finite),
+            None => ((Fp2Of::<C>::zero(), Fp2Of::<C>::zero()), TorusFlags::Identity),
+        };
+        z.serialize_with_mode(OutputAsWrite(&mut *dest), Compress::No).expect(OOPS);
+        y.serialize_with_flags(OutputAsWrite(dest), flags).expect(OOPS);
+    }
+
+    fn encoded_size(&self) -> usize {
+        compressed6_len::<C>()
+    }
+}
+
+impl<P, C, const U: Usage> Decode for Torus6<PairingOutput<P>, U>
+where
+    P: Pairing<TargetField = Fp12<C>>,
+    C: Fp12Config,
+{
+    fn decode<I: Input>(input: &mut I) -> Result<Self, scale::Error> {
+        let z = <Fp2Of<C> as CanonicalDeserialize>::deserialize_with_mode(
+            InputAsRead(&mut *input),
+            Compress::No,
+            is_validated(U),
+        )
+        .map_err(ark_error_to_scale_error)?;
+        let (y, flags) = <Fp2Of<C> as CanonicalDeserializeWithFlags>::deserialize_with_flags(InputAsRead(input))
+            .map_err(ark_error_to_scale_error)?;
+        let g = match flags {
+            TorusFlags::Finite => t6_decompress::<C>(&z, &y).ok_or("Torus6 compressed element is not on the torus")?,
+            TorusFlags::Identity if z.is_zero() && y.is_zero() => Fp12::one(),
+            TorusFlags::Identity => return Err("Torus6 compressed identity is not canonical".into()),
+        };
+        if is_validated(U) == Validate::Yes && !g.pow(P::ScalarField::characteristic()).is_one() {
+            return Err("Torus6 compressed element is not in the pairing target group".into());
+        }
+        Ok(Torus6(PairingOutput(g)))
+    }
+
+    fn encoded_fixed_size() -> Option<usize> {
+        Some(compressed6_len::<C>())
+    }
+}
+
+impl<P, C, const U: Usage> MaxEncodedLen for Torus6<PairingOutput<P>, U>
+where
+    P: Pairing<TargetField = Fp12<C>>,
+    C: Fp12Config,
+{
+    #[inline]
+    fn max_encoded_len() -> usize {
+        compressed6_len::<C>()
+    }
+}
+
+impl<P, C, const U: Usage> TypeInfo for Torus6<PairingOutput<P>, U>
+where
+    P: Pairing<TargetField = Fp12<C>>,
+    C: Fp12Config,
+{
+    type Identity = Self;
+
+    fn type_info() -> scale_info::Type {
+        let path = scale_info::Path::new("Torus6", module_path!());
+        let array_type_def = scale_info::TypeDefArray {
+            len: compressed6_len::<C>() as u32,
+            type_param: scale_info::MetaType::new::<u8>(),
+        };
+        let type_def = scale_info::TypeDef::Array(array_type_def);
+        scale_info::Type { path, type_params: Vec::new(), type_def, docs: Vec::new() }
+    }
+}
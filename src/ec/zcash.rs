@@ -0,0 +1,207 @@
+//! # Zcash encoding for BLS12 curves
+//!
+//! The de facto interoperable BLS12-381 encoding, used by zkcrypto,
+//! blst, Ethereum consensus, and the IETF BLS signature drafts, writes
+//! base field elements as big-endian integers, `Fp2` elements as
+//! `c1 || c0`, and uses the top three bits of the first byte as flags:
+//! compression, infinity, and, only when compressed, whether `y` is
+//! the lexicographically largest square root.
+//!
+//! `Zcash<T, U>` wraps `bls12::G1Affine` and `bls12::G2Affine` for
+//! SCALE in this layout, using `U` for compression and validation
+//! like `ArkScale<T, U>` does.  We never rely upon the arkworks curve
+//! crate itself serializing this way.
+//!
+//! As with `Eip2537`, we cannot bound upon `Bls12Config` itself, so we
+//! accept curves over 6 limb prime fields or their quadratic extensions,
+//! and check for the three spare flag bits when compiling.
+
+use ark_std::{vec, vec::Vec};
+
+use ark_ec::{models::short_weierstrass as sw, AffineRepr};
+use ark_ff::{fields::models::*, Field, PrimeField};
+use ark_serialize::{Compress, Validate};
+
+use crate::{
+    ff::{ff_from_be_bytes, ff_to_be_bytes, fp_be_len},
+    is_compressed, is_validated,
+    scale::{self, Decode, Encode, EncodeLike, Input, MaxEncodedLen, Output},
+    Usage, WIRE,
+};
+use scale_info::TypeInfo;
+
+const COMPRESSION_FLAG: u8 = 0x80;
+const INFINITY_FLAG: u8 = 0x40;
+const SORT_FLAG: u8 = 0x20;
+const FLAGS: u8 = COMPRESSION_FLAG | INFINITY_FLAG | SORT_FLAG;
+
+/// Arkworks type wrapped for serialization by SCALE in Zcash layout
+#[derive(Clone, Eq, PartialEq, Debug)]
+#[repr(transparent)]
+pub struct Zcash<T, const U: Usage = WIRE>(pub T);
+
+impl<T, const U: Usage> From<T> for Zcash<T, U> {
+    fn from(t: T) -> Zcash<T, U> {
+        Zcash(t)
+    }
+}
+
+/// Base fields of BLS12 curves, either 6 limb prime fields with three
+/// spare bits for G1, or their quadratic extensions for G2.
+pub trait ZcashBaseField: Field {
+    /// Width of one base prime field element
+    const WIDTH: usize;
+    /// Width of one base field element
+    const LEN: usize;
+}
+
+impl<P: FpConfig<6>> ZcashBaseField for Fp<P, 6> {
+    const WIDTH: usize = {
+        assert!(
+            Self::MODULUS_BIT_SIZE as usize + 3 <= 8 * fp_be_len::<Self>(),
+            "Zcash encoding needs three spare bits in the base field."
+        );
+        fp_be_len::<Self>()
+    };
+    const LEN: usize = Self::WIDTH;
+}
+
+impl<Q: FpConfig<6>, P: QuadExtConfig<BaseField = Fp<Q, 6>>> ZcashBaseField for QuadExtField<P> {
+    const WIDTH: usize = <Fp<Q, 6> as ZcashBaseField>::WIDTH;
+    const LEN: usize = 2 * Self::WIDTH;
+}
+
+fn point_len<C: sw::SWCurveConfig>(compress: Compress) -> usize
+where
+    C::BaseField: ZcashBaseField,
+{
+    match compress {
+        Compress::Yes => C::BaseField::LEN,
+        Compress::No => 2 * C::BaseField::LEN,
+    }
+}
+
+/// Big-endian base field element with `Fp2` as `c1 || c0`.
+fn fq_to_bytes<F: ZcashBaseField>(f: &F) -> Vec<u8> {
+    ff_to_be_bytes(f, F::WIDTH, true)
+}
+
+fn fq_from_bytes<F: ZcashBaseField>(bytes: &[u8]) -> Result<F, scale::Error> {
+    ff_from_be_bytes(bytes, F::WIDTH, true).ok_or_else(|| "Zcash base field element is not canonical".into())
+}
+
+impl<C: sw::SWCurveConfig, const U: Usage> EncodeLike for Zcash<sw::Affine<C>, U> where C::BaseField: ZcashBaseField {}
+
+impl<C: sw::SWCurveConfig, const U: Usage> Encode for Zcash<sw::Affine<C>, U>
+where
+    C::BaseField: ZcashBaseField,
+{
+    fn size_hint(&self) -> usize {
+        point_len::<C>(is_compressed(U))
+    }
+
+    fn encode_to<O: Output + ?Sized>(&self, dest: &mut O) {
+        let compress = is_compressed(U);
+        let mut bytes = match self.0.xy() {
+            None => {
+                let mut bytes = vec![0u8; point_len::<C>(compress)];
+                bytes[0] |= INFINITY_FLAG;
+                bytes
+            }
+            Some((x, y)) => {
+                let mut bytes = fq_to_bytes(&x);
+                match compress {
+                    Compress::Yes if y > -y => bytes[0] |= SORT_FLAG,
+                    Compress::Yes => {}
+                    Compress::No => bytes.extend_from_slice(&fq_to_bytes(&y)),
+                }
+                bytes
+            }
+        };
+        if compress == Compress::Yes {
+            bytes[0] |= COMPRESSION_FLAG;
+        }
+        dest.write(&bytes);
+    }
+
+    fn encoded_size(&self) -> usize {
+        point_len::<C>(is_compressed(U))
+    }
+}
+
+impl<C: sw::SWCurveConfig, const U: Usage> Decode for Zcash<sw::Affine<C>, U>
+where
+    C::BaseField: ZcashBaseField,
+{
+    fn decode<I: Input>(input: &mut I) -> Result<Self, scale::Error> {
+        let compress = is_compressed(U);
+        let mut bytes = vec![0u8; point_len::<C>(compress)];
+        input.read(&mut bytes)?;
+        let flags = bytes[0] & FLAGS;
+        bytes[0] &= !FLAGS;
+
+        if (flags & COMPRESSION_FLAG != 0) != (compress == Compress::Yes) {
+            return Err("Zcash point has the wrong compression flag".into());
+        }
+        if flags & INFINITY_FLAG != 0 {
+            if flags & SORT_FLAG != 0 || bytes.iter().any(|b| *b != 0) {
+                return Err("Zcash point at infinity is not canonical".into());
+            }
+            return Ok(Zcash(sw::Affine::identity()));
+        }
+
+        let p = match compress {
+            Compress::Yes => {
+                let x = fq_from_bytes::<C::BaseField>(&bytes)?;
+                sw::Affine::get_point_from_x_unchecked(x, flags & SORT_FLAG != 0)
+                    .ok_or("Zcash point is not on the curve")?
+            }
+            Compress::No => {
+                if flags & SORT_FLAG != 0 {
+                    return Err("Zcash uncompressed point has the sort flag".into());
+                }
+                let (x, y) = bytes.split_at(bytes.len() / 2);
+                let p = sw::Affine::new_unchecked(fq_from_bytes::<C::BaseField>(x)?, fq_from_bytes::<C::BaseField>(y)?);
+                if is_validated(U) == Validate::Yes && !p.is_on_curve() {
+                    return Err("Zcash point is not on the curve".into());
+                }
+                p
+            }
+        };
+        if is_validated(U) == Validate::Yes && !p.is_in_correct_subgroup_assuming_on_curve() {
+            return Err("Zcash point is not in the prime order subgroup".into());
+        }
+        Ok(Zcash(p))
+    }
+
+    fn encoded_fixed_size() -> Option<usize> {
+        Some(point_len::<C>(is_compressed(U)))
+    }
+}
+
+impl<C: sw::SWCurveConfig, const U: Usage> MaxEncodedLen for Zcash<sw::Affine<C>, U>
+where
+    C::BaseField: ZcashBaseField,
+{
+    #[inline]
+    fn max_encoded_len() -> usize {
+        point_len::<C>(is_compressed(U))
+    }
+}
+
+impl<C: sw::SWCurveConfig, const U: Usage> TypeInfo for Zcash<sw::Affine<C>, U>
+where
+    C::BaseField: ZcashBaseField,
+{
+    type Identity = Self;
+
+    fn type_info() -> scale_info::Type {
+        let path = scale_info::Path::new("Zcash", module_path!());
+        let array_type_def = scale_info::TypeDefArray {
+            len: point_len::<C>(is_compressed(U)) as u32,
+            type_param: scale_info::MetaType::new::<u8>(),
+        };
+        let type_def = scale_info::TypeDef::Array(array_type_def);
+        scale_info::Type { path, type_params: Vec::new(), type_def, docs: Vec::new() }
+    }
+}
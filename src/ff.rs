@@ -1,7 +1,9 @@
 
-use crate::{self as ark_scale, ArkScaleMaxEncodedLen}; // ArkScale,ArkScaleRef,ConstEncodedLen
+use crate::{self as ark_scale, ArkScale, ArkScaleMaxEncodedLen, Usage, WIRE}; // ArkScaleRef,ConstEncodedLen
+use crate::scale::{self, Decode, Input};
 
-use ark_ff::fields::models::*;
+use ark_ff::{fields::models::*, BigInteger, Field, PrimeField};
+use ark_std::{vec, vec::Vec};
 
 
 impl<P: FpConfig<N>, const N: usize> ArkScaleMaxEncodedLen for Fp<P,N> {
@@ -16,3 +18,90 @@ impl<C: CubicExtConfig> ArkScaleMaxEncodedLen for CubicExtField<C> {
     crate::impl_body_max_encode_len!();
 }
 
+crate::impl_from_ark_scale!(Fp<P, N>; P: FpConfig<N>, const N: usize);
+crate::impl_from_ark_scale!(QuadExtField<C>; C: QuadExtConfig);
+crate::impl_from_ark_scale!(CubicExtField<C>; C: CubicExtConfig);
+
+/// Field element which never decodes to zero, suitable for secret
+/// scalars.
+///
+/// We otherwise decode exactly like `ArkScale<F, U>`.
+#[derive(Clone, Eq, PartialEq, Debug)]
+#[repr(transparent)]
+pub struct NonZero<F, const U: Usage = WIRE>(pub F);
+
+impl<F, const U: Usage> From<F> for NonZero<F, U> {
+    fn from(f: F) -> NonZero<F, U> {
+        NonZero(f)
+    }
+}
+
+impl<F: Field, const U: Usage> Decode for NonZero<F, U> {
+    fn decode<I: Input>(input: &mut I) -> Result<Self, scale::Error> {
+        let f = <ArkScale<F, U> as Decode>::decode(input)?.0;
+        if f.is_zero() {
+            return Err("Field element is zero".into());
+        }
+        Ok(NonZero(f))
+    }
+}
+
+crate::impl_forward_to_ark_scale!(NonZero);
+
+
+// Big-endian helpers for interoperable encodings, which unlike
+// arkworks all use big-endian integers.
+
+/// Write the canonical big-endian integer representation of `f`
+/// into `dest`, left padded with zeros.
+///
+/// Panics if `dest` cannot hold the integer.
+pub fn fp_to_be_bytes<F: PrimeField>(f: &F, dest: &mut [u8]) {
+    let b = f.into_bigint().to_bytes_be();
+    let (hi, lo) = b.split_at(b.len().saturating_sub(dest.len()));
+    assert!(hi.iter().all(|x| *x == 0), "Field element does not fit into big-endian buffer.");
+    let pad = dest.len() - lo.len();
+    dest[..pad].fill(0);
+    dest[pad..].copy_from_slice(lo);
+}
+
+/// Read the canonical big-endian integer representation of a prime
+/// field element, possibly left padded with zeros.
+///
+/// Returns `None` if the integer is not less than the modulus.
+pub fn fp_from_be_bytes<F: PrimeField>(src: &[u8]) -> Option<F> {
+    let f = F::from_be_bytes_mod_order(src);
+    let mut b = vec![0u8; src.len()];
+    fp_to_be_bytes(&f, &mut b);
+    if b.as_slice() == src { Some(f) } else { None }
+}
+
+/// Length in bytes of the minimal big-endian encoding of the prime
+/// field `F`.
+pub const fn fp_be_len<F: PrimeField>() -> usize {
+    F::MODULUS_BIT_SIZE.div_ceil(8) as usize
+}
+
+/// Big-endian coefficients of `f` over its base prime field, each
+/// padded to `width` bytes, in arkworks' order or reversed.
+pub fn ff_to_be_bytes<F: Field>(f: &F, width: usize, reverse: bool) -> Vec<u8> {
+    let coeffs: Vec<F::BasePrimeField> = f.to_base_prime_field_elements().collect();
+    let mut out = vec![0u8; width * coeffs.len()];
+    for (i, chunk) in out.chunks_exact_mut(width).enumerate() {
+        let j = if reverse { coeffs.len() - 1 - i } else { i };
+        fp_to_be_bytes(&coeffs[j], chunk);
+    }
+    out
+}
+
+/// Inverse of `ff_to_be_bytes`, which rejects non-canonical coefficients.
+pub fn ff_from_be_bytes<F: Field>(src: &[u8], width: usize, reverse: bool) -> Option<F> {
+    let mut coeffs = src
+        .chunks_exact(width)
+        .map(fp_from_be_bytes::<F::BasePrimeField>)
+        .collect::<Option<Vec<_>>>()?;
+    if reverse {
+        coeffs.reverse();
+    }
+    F::from_base_prime_field_elems(coeffs)
+}
@@ -5,14 +5,56 @@
 //! Aside from not being cannonical, we caution that projective coordinates
 //! actually [leak secret key material](https://eprint.iacr.org/2003/191),
 //! which makes them extraordinarily dangerous.
+//! `ArkScaleProjective<Normalized<T>>` avoids both problems by
+//! normalizing to `Z = 1` before encoding.
 //!
-//! We do not implement curve or subgroup check for elliptic curve points
-//! in projective coordinates, nor do we validate their base field elements.
-//! You diserve to be p0wned if you use them in an untrusted enviroment.
+//! `ArkScaleProjective` does not implement curve or subgroup check for
+//! elliptic curve points in projective coordinates, nor do we ever
+//! validate their base field elements.  You diserve to be p0wned if you
+//! use them in an untrusted enviroment.
+//! `ArkScaleProjectiveChecked<T, CHECKED>` sanity checks projective
+//! points when decoding, like host call results, but without
+//! normalizing them.
+//!
+//! We similarly copy raw Montgomery limbs in `montgomery`, which
+//! skips even the reduction of base field elements.
 
 use super::*;
 pub use ark_ec::models::{short_weierstrass as sw, twisted_edwards as te};
-
+use ark_ec::{CurveGroup, PrimeGroup};
+use ark_ff::{BitIteratorBE, Field, Zero};
+
+pub mod montgomery;
+pub use montgomery::{ArkScaleMontgomery, MontgomeryLimbs};
+
+/// Checks made when decoding `ArkScaleProjectiveChecked` points,
+/// morally a pair of booleans, like `Usage`.  We never check when
+/// encoding, so see `Normalized` for normalizing.
+pub type ProjectiveMode = u8;
+
+/// Decode projective coordinates as is, without any checks,
+/// like `ArkScaleProjective`
+pub const UNCHECKED: ProjectiveMode = 0;
+
+/// Check the projective curve equation, and for twisted Edwards
+/// curves that `Z != 0` and the extended coordinate `T Z = X Y`.
+pub const ON_CURVE: ProjectiveMode = 1;
+
+/// Check membership in the prime order subgroup by multiplying by
+/// the scalar field modulus, which costs one scalar multiplication.
+pub const IN_SUBGROUP: ProjectiveMode = 2;
+
+/// Decoding with all checks
+pub const CHECKED: ProjectiveMode = ON_CURVE | IN_SUBGROUP;
+
+/// Projective point, or vector or slice thereof, wrapped for
+/// serialization by SCALE without any checks.  Wrap the point in
+/// `Normalized` to encode it with `Z = 1`.
+///
+/// We compare points as group elements, not coordinates, like arkworks.
+/// `Decode` cannot yield the `&T` and `&[T]` variants, so their
+/// `decode_owned` decodes into the owned `T` and `Vec<T>` variants.
+#[derive(Clone, Copy, Eq, PartialEq, Debug)]
 pub struct ArkScaleProjective<T>(pub T);
 
 impl<T> From<T> for ArkScaleProjective<T> {
@@ -21,12 +63,125 @@ impl<T> From<T> for ArkScaleProjective<T> {
     }
 }
 
+impl<T> ArkScaleProjective<&T>
+where
+    ArkScaleProjective<T>: Decode,
+{
+    /// Decode the encoding of `ArkScaleProjective<&T>` into an owned `T`.
+    pub fn decode_owned<I: Input>(input: &mut I) -> Result<ArkScaleProjective<T>, scale::Error> {
+        ArkScaleProjective::<T>::decode(input)
+    }
+}
+
+impl<T> ArkScaleProjective<&[T]>
+where
+    ArkScaleProjective<Vec<T>>: Decode,
+{
+    /// Decode the encoding of `ArkScaleProjective<&[T]>` into a `Vec<T>`.
+    pub fn decode_owned<I: Input>(input: &mut I) -> Result<ArkScaleProjective<Vec<T>>, scale::Error> {
+        ArkScaleProjective::<Vec<T>>::decode(input)
+    }
+}
+
+/// Projective point, or vector or slice thereof, wrapped for
+/// serialization by SCALE with the decoding checks `K`, with the same
+/// encoding as `ArkScaleProjective`.
+///
+/// We compare points as group elements, not coordinates, like arkworks.
+/// As with `ArkScaleProjective`, `decode_owned` decodes the encodings
+/// of the `&T` and `&[T]` variants, with the same checks.
+#[derive(Clone, Copy, Eq, PartialEq, Debug)]
+pub struct ArkScaleProjectiveChecked<T, const K: ProjectiveMode>(pub T);
+
+impl<T, const K: ProjectiveMode> From<T> for ArkScaleProjectiveChecked<T, K> {
+    fn from(t: T) -> ArkScaleProjectiveChecked<T, K> {
+        ArkScaleProjectiveChecked(t)
+    }
+}
+
+impl<T, const K: ProjectiveMode> ArkScaleProjectiveChecked<&T, K>
+where
+    ArkScaleProjectiveChecked<T, K>: Decode,
+{
+    /// Decode the encoding of `ArkScaleProjectiveChecked<&T, K>` into
+    /// an owned `T`.
+    pub fn decode_owned<I: Input>(input: &mut I) -> Result<ArkScaleProjectiveChecked<T, K>, scale::Error> {
+        ArkScaleProjectiveChecked::<T, K>::decode(input)
+    }
+}
+
+impl<T, const K: ProjectiveMode> ArkScaleProjectiveChecked<&[T], K>
+where
+    ArkScaleProjectiveChecked<Vec<T>, K>: Decode,
+{
+    /// Decode the encoding of `ArkScaleProjectiveChecked<&[T], K>` into
+    /// a `Vec<T>`.
+    pub fn decode_owned<I: Input>(input: &mut I) -> Result<ArkScaleProjectiveChecked<Vec<T>, K>, scale::Error> {
+        ArkScaleProjectiveChecked::<Vec<T>, K>::decode(input)
+    }
+}
+
+/// Projective point, or vector or slice thereof, which
+/// `ArkScaleProjective<Normalized<T>>` normalizes to `Z = 1` before
+/// encoding, which yields canonical bytes and hides the secret
+/// dependent `Z`.  Vectors and slices normalize with one batched
+/// inversion.
+///
+/// We only encode normalized points, so decode them as
+/// `ArkScaleProjective<T>` or `ArkScaleProjectiveChecked<T, K>`,
+/// which share the encoding.
+#[derive(Clone, Copy, Debug)]
+pub struct Normalized<T>(pub T);
+
+/// Multiply by the scalar field modulus using double-and-add, because
+/// curves like BLS12-381 override `mul_bigint` by endomorphisms which
+/// agree with scalar multiplication only inside the prime order subgroup.
+fn mul_by_modulus<G: PrimeGroup>(p: &G) -> G {
+    let mut r = G::zero();
+    for bit in BitIteratorBE::without_leading_zeros(G::ScalarField::characteristic()) {
+        r.double_in_place();
+        if bit {
+            r += p;
+        }
+    }
+    r
+}
+
+fn check_projective<G, F>(p: &G, on_curve: F, k: ProjectiveMode) -> Result<(), scale::Error>
+where
+    G: PrimeGroup,
+    F: FnOnce(&G) -> bool,
+{
+    if k & ON_CURVE != 0 && !on_curve(p) {
+        return Err("Projective point is not on the curve".into());
+    }
+    if k & IN_SUBGROUP != 0 && !mul_by_modulus(p).is_zero() {
+        return Err("Projective point is not in the prime order subgroup".into());
+    }
+    Ok(())
+}
+
 /// Uncompressed mode since projective coordinates are non-cannonical and leaks secrets
 const MC: Compress = Compress::No;
 const MV: Validate = Validate::No;
 
+fn skip_bytes<I: Input>(input: &mut I, mut len: usize) -> Result<(), scale::Error> {
+    let mut buf = [0u8; 64];
+    while len > 0 {
+        let n = len.min(buf.len());
+        input.read(&mut buf[..n])?;
+        len -= n;
+    }
+    Ok(())
+}
+
 // Short Weierstrass //
 
+/// Length of `X`, `Y`, and `Z` in Jacobian coordinates
+fn sw_len<C: sw::SWCurveConfig>() -> usize {
+    3 * C::BaseField::zero().serialized_size(MC)
+}
+
 pub fn ark_sw_encode_to<W, C>(p: &sw::Projective<C>, dest: &mut W) -> Result<(), SerializationError>
 where
     W: Write + ?Sized,
@@ -45,34 +200,6 @@ where
     ark_sw_encode_to(p, &mut OutputAsWrite(dest)).expect(OOPS);
 }
 
-impl<C: sw::SWCurveConfig> Encode for ArkScaleProjective<sw::Projective<C>> {
-    fn size_hint(&self) -> usize {
-        3 * self.0.x.serialized_size(MC)
-    }
-
-    fn encode_to<O: Output + ?Sized>(&self, dest: &mut O) {
-        scale_sw_encode_to(&self.0.borrow(), dest)
-    }
-
-    fn encoded_size(&self) -> usize {
-        3 * self.0.serialized_size(MC)
-    }
-}
-
-impl<'a, C: sw::SWCurveConfig> Encode for ArkScaleProjective<&'a sw::Projective<C>> {
-    fn size_hint(&self) -> usize {
-        3 * self.0.x.serialized_size(MC)
-    }
-
-    fn encode_to<O: Output + ?Sized>(&self, dest: &mut O) {
-        scale_sw_encode_to(&self.0.borrow(), dest)
-    }
-
-    fn encoded_size(&self) -> usize {
-        3 * self.0.serialized_size(MC)
-    }
-}
-
 pub fn ark_sw_decode_from<R, C>(src: &mut R) -> Result<sw::Projective<C>, SerializationError>
 where
     R: Read,
@@ -92,18 +219,35 @@ where
     ark_sw_decode_from(&mut InputAsRead(src)).map_err(ark_error_to_scale_error)
 }
 
-impl<C: sw::SWCurveConfig> Decode for ArkScaleProjective<sw::Projective<C>> {
-    fn decode<I: Input>(input: &mut I) -> Result<Self, scale::Error> {
-        scale_sw_decode_from(input).map(|p| ArkScaleProjective(p))
+/// Check the Jacobian curve equation `Y^2 = X^3 + a X Z^4 + b Z^6`,
+/// which holds for the identity `(t^2, t^3, 0)` too, but not `(0, 0, 0)`.
+pub fn sw_is_on_curve<C: sw::SWCurveConfig>(p: &sw::Projective<C>) -> bool {
+    if p.x.is_zero() && p.y.is_zero() && p.z.is_zero() {
+        return false;
     }
+    let z2 = p.z.square();
+    let z4 = z2.square();
+    let rhs = p.x.square() * p.x + C::mul_by_a(p.x * z4) + C::COEFF_B * z4 * z2;
+    p.y.square() == rhs
+}
 
-    // fn skip<I: Input>(input: &mut I) -> Result<(), Error> { ... }
-
-    // fn encoded_fixed_size() -> Option<usize> { ... }
+fn sw_decode_checked<I, C>(src: &mut I, k: ProjectiveMode) -> Result<sw::Projective<C>, scale::Error>
+where
+    I: Input,
+    C: sw::SWCurveConfig,
+{
+    let p = scale_sw_decode_from(src)?;
+    check_projective(&p, sw_is_on_curve, k)?;
+    Ok(p)
 }
 
 // Twisted Edwards //
 
+/// Length of `X`, `Y`, `T`, and `Z` in extended coordinates
+fn te_len<C: te::TECurveConfig>() -> usize {
+    4 * C::BaseField::zero().serialized_size(MC)
+}
+
 pub fn ark_te_encode_to<W, C>(p: &te::Projective<C>, dest: &mut W) -> Result<(), SerializationError>
 where
     W: Write + ?Sized,
@@ -123,34 +267,6 @@ where
     ark_te_encode_to(p, &mut OutputAsWrite(dest)).expect(OOPS);
 }
 
-impl<C: te::TECurveConfig> Encode for ArkScaleProjective<te::Projective<C>> {
-    fn size_hint(&self) -> usize {
-        4 * self.0.x.serialized_size(MC)
-    }
-
-    fn encode_to<O: Output + ?Sized>(&self, dest: &mut O) {
-        scale_te_encode_to(&self.0, dest)
-    }
-
-    fn encoded_size(&self) -> usize {
-        4 * self.0.serialized_size(MC)
-    }
-}
-
-impl<'a, C: te::TECurveConfig> Encode for ArkScaleProjective<&'a te::Projective<C>> {
-    fn size_hint(&self) -> usize {
-        4 * self.0.x.serialized_size(MC)
-    }
-
-    fn encode_to<O: Output + ?Sized>(&self, dest: &mut O) {
-        scale_te_encode_to(&self.0, dest)
-    }
-
-    fn encoded_size(&self) -> usize {
-        4 * self.0.serialized_size(MC)
-    }
-}
-
 pub fn ark_te_decode_from<R, C>(src: &mut R) -> Result<te::Projective<C>, SerializationError>
 where
     R: Read,
@@ -171,12 +287,261 @@ where
     ark_te_decode_from(&mut InputAsRead(src)).map_err(ark_error_to_scale_error)
 }
 
-impl<C: te::TECurveConfig> Decode for ArkScaleProjective<te::Projective<C>> {
-    fn decode<I: Input>(input: &mut I) -> Result<Self, scale::Error> {
-        scale_te_decode_from(input).map(|p| ArkScaleProjective(p))
+/// Check the extended twisted Edwards curve equation
+/// `(a X^2 + Y^2) Z^2 = Z^4 + d X^2 Y^2`, along with `Z != 0`
+/// and `T Z = X Y`.
+pub fn te_is_on_curve<C: te::TECurveConfig>(p: &te::Projective<C>) -> bool {
+    if p.z.is_zero() || p.t * p.z != p.x * p.y {
+        return false;
     }
+    let (x2, y2, z2) = (p.x.square(), p.y.square(), p.z.square());
+    (C::mul_by_a(x2) + y2) * z2 == z2.square() + C::COEFF_D * x2 * y2
+}
+
+fn te_decode_checked<I, C>(src: &mut I, k: ProjectiveMode) -> Result<te::Projective<C>, scale::Error>
+where
+    I: Input,
+    C: te::TECurveConfig,
+{
+    let p = scale_te_decode_from(src)?;
+    check_projective(&p, te_is_on_curve, k)?;
+    Ok(p)
+}
 
-    // fn skip<I: Input>(input: &mut I) -> Result<(), Error> { ... }
+// Wrappers //
+
+/// Implements the SCALE traits for the wrapper `$w`, whose type
+/// arguments after the point are `$kp`, and generic parameters after
+/// the curve config are `$gen`, decoding with checks `$k`.
+///
+/// Sequences use a `u64` little-endian length prefix, like arkworks.
+macro_rules! impl_projective {
+    ($m:ident, $c:path, $len:ident, $encode_to:ident, $decode:ident,
+     $w:ident [$($kp:tt)*], [$($gen:tt)*], $k:expr) => {
+        impl<C: $c $($gen)*> EncodeLike for $w<$m::Projective<C> $($kp)*> {}
+
+        impl<C: $c $($gen)*> EncodeLike<$w<$m::Projective<C> $($kp)*>> for $w<&$m::Projective<C> $($kp)*> {}
+
+        impl<C: $c $($gen)*> Encode for $w<$m::Projective<C> $($kp)*> {
+            fn size_hint(&self) -> usize {
+                $len::<C>()
+            }
+
+            fn encode_to<O: Output + ?Sized>(&self, dest: &mut O) {
+                $w::<_ $($kp)*>(&self.0).encode_to(dest)
+            }
+
+            fn encoded_size(&self) -> usize {
+                $len::<C>()
+            }
+        }
+
+        impl<C: $c $($gen)*> Encode for $w<&$m::Projective<C> $($kp)*> {
+            fn size_hint(&self) -> usize {
+                $len::<C>()
+            }
+
+            fn encode_to<O: Output + ?Sized>(&self, dest: &mut O) {
+                $encode_to(self.0, dest)
+            }
+
+            fn encoded_size(&self) -> usize {
+                $len::<C>()
+            }
+        }
+
+        impl<C: $c $($gen)*> Decode for $w<$m::Projective<C> $($kp)*> {
+            fn decode<I: Input>(input: &mut I) -> Result<Self, scale::Error> {
+                $decode(input, $k).map($w)
+            }
+
+            fn skip<I: Input>(input: &mut I) -> Result<(), scale::Error> {
+                skip_bytes(input, $len::<C>())
+            }
+
+            fn encoded_fixed_size() -> Option<usize> {
+                Some($len::<C>())
+            }
+        }
+
+        impl<C: $c $($gen)*> MaxEncodedLen for $w<$m::Projective<C> $($kp)*> {
+            #[inline]
+            fn max_encoded_len() -> usize {
+                $len::<C>()
+            }
+        }
+
+        impl<C: $c $($gen)*> MaxEncodedLen for $w<&$m::Projective<C> $($kp)*> {
+            #[inline]
+            fn max_encoded_len() -> usize {
+                $len::<C>()
+            }
+        }
+
+        impl<C: $c $($gen)*> TypeInfo for $w<$m::Projective<C> $($kp)*> {
+            type Identity = Self;
+
+            fn type_info() -> scale_info::Type {
+                let path = scale_info::Path::new(stringify!($w), module_path!());
+                let array_type_def = scale_info::TypeDefArray {
+                    len: $len::<C>() as u32,
+                    type_param: scale_info::MetaType::new::<u8>(),
+                };
+                let type_def = scale_info::TypeDef::Array(array_type_def);
+                scale_info::Type { path, type_params: Vec::new(), type_def, docs: Vec::new() }
+            }
+        }
+
+        impl<C: $c $($gen)*> TypeInfo for $w<&'static $m::Projective<C> $($kp)*> {
+            type Identity = $w<$m::Projective<C> $($kp)*>;
+
+            fn type_info() -> scale_info::Type {
+                <Self::Identity as TypeInfo>::type_info()
+            }
+        }
+
+        impl<C: $c $($gen)*> Encode for $w<&[$m::Projective<C>] $($kp)*> {
+            fn size_hint(&self) -> usize {
+                8 + self.0.len() * $len::<C>()
+            }
+
+            fn encode_to<O: Output + ?Sized>(&self, dest: &mut O) {
+                (self.0.len() as u64).encode_to(dest);
+                for p in self.0 {
+                    $encode_to(p, dest);
+                }
+            }
+        }
+
+        impl<C: $c $($gen)*> EncodeLike for $w<Vec<$m::Projective<C>> $($kp)*> {}
+
+        impl<C: $c $($gen)*> EncodeLike<$w<Vec<$m::Projective<C>> $($kp)*>> for $w<&[$m::Projective<C>] $($kp)*> {}
+
+        impl<C: $c $($gen)*> Encode for $w<Vec<$m::Projective<C>> $($kp)*> {
+            fn size_hint(&self) -> usize {
+                $w::<&[_] $($kp)*>(&self.0).size_hint()
+            }
+
+            fn encode_to<O: Output + ?Sized>(&self, dest: &mut O) {
+                $w::<&[_] $($kp)*>(&self.0).encode_to(dest)
+            }
+        }
+
+        impl<C: $c $($gen)*> Decode for $w<Vec<$m::Projective<C>> $($kp)*> {
+            fn decode<I: Input>(input: &mut I) -> Result<Self, scale::Error> {
+                let len = u64::decode(input)?;
+                // Never trust the length prefix for allocation.
+                let mut v = Vec::new();
+                for _ in 0..len {
+                    v.push($decode(input, $k)?);
+                }
+                Ok($w(v))
+            }
+        }
+    };
+}
 
-    // fn encoded_fixed_size() -> Option<usize> { ... }
+impl_projective!(sw, sw::SWCurveConfig, sw_len, scale_sw_encode_to, sw_decode_checked, ArkScaleProjective[], [], UNCHECKED);
+impl_projective!(te, te::TECurveConfig, te_len, scale_te_encode_to, te_decode_checked, ArkScaleProjective[], [], UNCHECKED);
+impl_projective!(
+    sw, sw::SWCurveConfig, sw_len, scale_sw_encode_to, sw_decode_checked,
+    ArkScaleProjectiveChecked[, K], [, const K: ProjectiveMode], K
+);
+impl_projective!(
+    te, te::TECurveConfig, te_len, scale_te_encode_to, te_decode_checked,
+    ArkScaleProjectiveChecked[, K], [, const K: ProjectiveMode], K
+);
+
+/// Implements `Encode` for `ArkScaleProjective<Normalized<_>>`, along
+/// with `EncodeLike` for the decodable wrappers.
+macro_rules! impl_normalized {
+    ($m:ident, $c:path, $len:ident, $encode_to:ident) => {
+        impl<C: $c> Encode for ArkScaleProjective<Normalized<$m::Projective<C>>> {
+            fn size_hint(&self) -> usize {
+                $len::<C>()
+            }
+
+            fn encode_to<O: Output + ?Sized>(&self, dest: &mut O) {
+                ArkScaleProjective(Normalized(&self.0 .0)).encode_to(dest)
+            }
+
+            fn encoded_size(&self) -> usize {
+                $len::<C>()
+            }
+        }
+
+        impl<C: $c> Encode for ArkScaleProjective<Normalized<&$m::Projective<C>>> {
+            fn size_hint(&self) -> usize {
+                $len::<C>()
+            }
+
+            fn encode_to<O: Output + ?Sized>(&self, dest: &mut O) {
+                $encode_to(&$m::Projective::from(self.0 .0.into_affine()), dest)
+            }
+
+            fn encoded_size(&self) -> usize {
+                $len::<C>()
+            }
+        }
+
+        impl<C: $c> MaxEncodedLen for ArkScaleProjective<Normalized<$m::Projective<C>>> {
+            #[inline]
+            fn max_encoded_len() -> usize {
+                $len::<C>()
+            }
+        }
+
+        impl<C: $c> MaxEncodedLen for ArkScaleProjective<Normalized<&$m::Projective<C>>> {
+            #[inline]
+            fn max_encoded_len() -> usize {
+                $len::<C>()
+            }
+        }
+
+        impl<C: $c> Encode for ArkScaleProjective<Normalized<&[$m::Projective<C>]>> {
+            fn size_hint(&self) -> usize {
+                8 + self.0 .0.len() * $len::<C>()
+            }
+
+            fn encode_to<O: Output + ?Sized>(&self, dest: &mut O) {
+                (self.0 .0.len() as u64).encode_to(dest);
+                for a in $m::Projective::normalize_batch(self.0 .0) {
+                    $encode_to(&$m::Projective::from(a), dest);
+                }
+            }
+        }
+
+        impl<C: $c> Encode for ArkScaleProjective<Normalized<Vec<$m::Projective<C>>>> {
+            fn size_hint(&self) -> usize {
+                ArkScaleProjective(Normalized(self.0 .0.as_slice())).size_hint()
+            }
+
+            fn encode_to<O: Output + ?Sized>(&self, dest: &mut O) {
+                ArkScaleProjective(Normalized(self.0 .0.as_slice())).encode_to(dest)
+            }
+        }
+
+        impl<C: $c> EncodeLike<ArkScaleProjective<$m::Projective<C>>> for ArkScaleProjective<Normalized<$m::Projective<C>>> {}
+
+        impl<C: $c> EncodeLike<ArkScaleProjective<$m::Projective<C>>> for ArkScaleProjective<Normalized<&$m::Projective<C>>> {}
+
+        impl<C: $c> EncodeLike<ArkScaleProjective<Vec<$m::Projective<C>>>> for ArkScaleProjective<Normalized<Vec<$m::Projective<C>>>> {}
+
+        impl<C: $c> EncodeLike<ArkScaleProjective<Vec<$m::Projective<C>>>> for ArkScaleProjective<Normalized<&[$m::Projective<C>]>> {}
+
+        impl<C: $c, const K: ProjectiveMode> EncodeLike<ArkScaleProjectiveChecked<$m::Projective<C>, K>>
+            for ArkScaleProjective<Normalized<$m::Projective<C>>> {}
+
+        impl<C: $c, const K: ProjectiveMode> EncodeLike<ArkScaleProjectiveChecked<$m::Projective<C>, K>>
+            for ArkScaleProjective<Normalized<&$m::Projective<C>>> {}
+
+        impl<C: $c, const K: ProjectiveMode> EncodeLike<ArkScaleProjectiveChecked<Vec<$m::Projective<C>>, K>>
+            for ArkScaleProjective<Normalized<Vec<$m::Projective<C>>>> {}
+
+        impl<C: $c, const K: ProjectiveMode> EncodeLike<ArkScaleProjectiveChecked<Vec<$m::Projective<C>>, K>>
+            for ArkScaleProjective<Normalized<&[$m::Projective<C>]>> {}
+    };
 }
+
+impl_normalized!(sw, sw::SWCurveConfig, sw_len, scale_sw_encode_to);
+impl_normalized!(te, te::TECurveConfig, te_len, scale_te_encode_to);
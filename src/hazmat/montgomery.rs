@@ -0,0 +1,239 @@
+//! # Raw Montgomery limb serialization
+//!
+//! Arkworks stores prime field elements in Montgomery form, so even
+//! `HOST_CALL` usage converts every field element out of Montgomery
+//! form when encoding and back into it when decoding.  We instead copy
+//! the internal `BigInt` limbs verbatim, which costs nothing but only
+//! works when both sides agree upon the modulus and its Montgomery
+//! representation, so usually the same arkworks build on both sides.
+//!
+//! We neither reduce nor validate anything, so an unreduced limb array
+//! decodes to an invalid field element upon which arkworks arithmetic
+//! may silently misbehave.  Curve points are never checked either.
+
+use ark_std::{marker::PhantomData, vec::Vec};
+
+use ark_ec::models::{short_weierstrass as sw, twisted_edwards as te};
+use ark_ff::{fields::models::*, BigInt};
+
+use crate::scale::{self, Decode, Encode, EncodeLike, Input, Output};
+
+/// Arkworks types which we copy as their raw Montgomery limbs.
+pub trait MontgomeryLimbs: Sized {
+    /// Length of the raw encoding of `self`
+    fn limbs_len(&self) -> usize;
+
+    /// Write the raw Montgomery limbs of `self`
+    fn encode_limbs<O: Output + ?Sized>(&self, dest: &mut O);
+
+    /// Read raw Montgomery limbs, without reduction or validation
+    fn decode_limbs<I: Input>(input: &mut I) -> Result<Self, scale::Error>;
+}
+
+/// Arkworks type wrapped for serialization by SCALE as raw
+/// Montgomery limbs, like `HOST_CALL` but without any conversion.
+#[derive(Clone, Eq, PartialEq, Debug)]
+#[repr(transparent)]
+pub struct ArkScaleMontgomery<T>(pub T);
+
+impl<T> From<T> for ArkScaleMontgomery<T> {
+    fn from(t: T) -> ArkScaleMontgomery<T> {
+        ArkScaleMontgomery(t)
+    }
+}
+
+impl<T: MontgomeryLimbs> EncodeLike for ArkScaleMontgomery<T> {}
+
+impl<T: MontgomeryLimbs> Encode for ArkScaleMontgomery<T> {
+    fn size_hint(&self) -> usize {
+        self.0.limbs_len()
+    }
+
+    fn encode_to<O: Output + ?Sized>(&self, dest: &mut O) {
+        self.0.encode_limbs(dest)
+    }
+
+    fn encoded_size(&self) -> usize {
+        self.0.limbs_len()
+    }
+}
+
+impl<T: MontgomeryLimbs> Decode for ArkScaleMontgomery<T> {
+    fn decode<I: Input>(input: &mut I) -> Result<Self, scale::Error> {
+        T::decode_limbs(input).map(ArkScaleMontgomery)
+    }
+}
+
+// Fields //
+
+impl<P: FpConfig<N>, const N: usize> MontgomeryLimbs for Fp<P, N> {
+    fn limbs_len(&self) -> usize {
+        8 * N
+    }
+
+    fn encode_limbs<O: Output + ?Sized>(&self, dest: &mut O) {
+        self.0 .0.encode_to(dest)
+    }
+
+    fn decode_limbs<I: Input>(input: &mut I) -> Result<Self, scale::Error> {
+        <[u64; N]>::decode(input).map(|limbs| Fp(BigInt(limbs), PhantomData))
+    }
+}
+
+impl<P: QuadExtConfig> MontgomeryLimbs for QuadExtField<P>
+where
+    P::BaseField: MontgomeryLimbs,
+{
+    fn limbs_len(&self) -> usize {
+        self.c0.limbs_len() + self.c1.limbs_len()
+    }
+
+    fn encode_limbs<O: Output + ?Sized>(&self, dest: &mut O) {
+        self.c0.encode_limbs(dest);
+        self.c1.encode_limbs(dest);
+    }
+
+    fn decode_limbs<I: Input>(input: &mut I) -> Result<Self, scale::Error> {
+        let c0 = P::BaseField::decode_limbs(input)?;
+        let c1 = P::BaseField::decode_limbs(input)?;
+        Ok(QuadExtField::new(c0, c1))
+    }
+}
+
+impl<P: CubicExtConfig> MontgomeryLimbs for CubicExtField<P>
+where
+    P::BaseField: MontgomeryLimbs,
+{
+    fn limbs_len(&self) -> usize {
+        self.c0.limbs_len() + self.c1.limbs_len() + self.c2.limbs_len()
+    }
+
+    fn encode_limbs<O: Output + ?Sized>(&self, dest: &mut O) {
+        self.c0.encode_limbs(dest);
+        self.c1.encode_limbs(dest);
+        self.c2.encode_limbs(dest);
+    }
+
+    fn decode_limbs<I: Input>(input: &mut I) -> Result<Self, scale::Error> {
+        let c0 = P::BaseField::decode_limbs(input)?;
+        let c1 = P::BaseField::decode_limbs(input)?;
+        let c2 = P::BaseField::decode_limbs(input)?;
+        Ok(CubicExtField::new(c0, c1, c2))
+    }
+}
+
+// Curves //
+
+impl<C: sw::SWCurveConfig> MontgomeryLimbs for sw::Affine<C>
+where
+    C::BaseField: MontgomeryLimbs,
+{
+    fn limbs_len(&self) -> usize {
+        self.x.limbs_len() + self.y.limbs_len() + 1
+    }
+
+    fn encode_limbs<O: Output + ?Sized>(&self, dest: &mut O) {
+        self.x.encode_limbs(dest);
+        self.y.encode_limbs(dest);
+        self.infinity.encode_to(dest);
+    }
+
+    fn decode_limbs<I: Input>(input: &mut I) -> Result<Self, scale::Error> {
+        let x = C::BaseField::decode_limbs(input)?;
+        let y = C::BaseField::decode_limbs(input)?;
+        let infinity = bool::decode(input)?;
+        Ok(sw::Affine { x, y, infinity })
+    }
+}
+
+impl<C: sw::SWCurveConfig> MontgomeryLimbs for sw::Projective<C>
+where
+    C::BaseField: MontgomeryLimbs,
+{
+    fn limbs_len(&self) -> usize {
+        self.x.limbs_len() + self.y.limbs_len() + self.z.limbs_len()
+    }
+
+    fn encode_limbs<O: Output + ?Sized>(&self, dest: &mut O) {
+        self.x.encode_limbs(dest);
+        self.y.encode_limbs(dest);
+        self.z.encode_limbs(dest);
+    }
+
+    fn decode_limbs<I: Input>(input: &mut I) -> Result<Self, scale::Error> {
+        let x = C::BaseField::decode_limbs(input)?;
+        let y = C::BaseField::decode_limbs(input)?;
+        let z = C::BaseField::decode_limbs(input)?;
+        Ok(sw::Projective::new_unchecked(x, y, z))
+    }
+}
+
+impl<C: te::TECurveConfig> MontgomeryLimbs for te::Affine<C>
+where
+    C::BaseField: MontgomeryLimbs,
+{
+    fn limbs_len(&self) -> usize {
+        self.x.limbs_len() + self.y.limbs_len()
+    }
+
+    fn encode_limbs<O: Output + ?Sized>(&self, dest: &mut O) {
+        self.x.encode_limbs(dest);
+        self.y.encode_limbs(dest);
+    }
+
+    fn decode_limbs<I: Input>(input: &mut I) -> Result<Self, scale::Error> {
+        let x = C::BaseField::decode_limbs(input)?;
+        let y = C::BaseField::decode_limbs(input)?;
+        Ok(te::Affine::new_unchecked(x, y))
+    }
+}
+
+impl<C: te::TECurveConfig> MontgomeryLimbs for te::Projective<C>
+where
+    C::BaseField: MontgomeryLimbs,
+{
+    fn limbs_len(&self) -> usize {
+        self.x.limbs_len() + self.y.limbs_len() + self.t.limbs_len() + self.z.limbs_len()
+    }
+
+    fn encode_limbs<O: Output + ?Sized>(&self, dest: &mut O) {
+        self.x.encode_limbs(dest);
+        self.y.encode_limbs(dest);
+        self.t.encode_limbs(dest);
+        self.z.encode_limbs(dest);
+    }
+
+    fn decode_limbs<I: Input>(input: &mut I) -> Result<Self, scale::Error> {
+        let x = C::BaseField::decode_limbs(input)?;
+        let y = C::BaseField::decode_limbs(input)?;
+        let t = C::BaseField::decode_limbs(input)?;
+        let z = C::BaseField::decode_limbs(input)?;
+        Ok(te::Projective::new_unchecked(x, y, t, z))
+    }
+}
+
+// Sequences //
+
+/// Vectors use a `u64` little-endian length prefix, like arkworks.
+impl<T: MontgomeryLimbs> MontgomeryLimbs for Vec<T> {
+    fn limbs_len(&self) -> usize {
+        8 + self.iter().map(|t| t.limbs_len()).sum::<usize>()
+    }
+
+    fn encode_limbs<O: Output + ?Sized>(&self, dest: &mut O) {
+        (self.len() as u64).encode_to(dest);
+        for t in self {
+            t.encode_limbs(dest);
+        }
+    }
+
+    fn decode_limbs<I: Input>(input: &mut I) -> Result<Self, scale::Error> {
+        let len = u64::decode(input)?;
+        // Never trust the length prefix for allocation.
+        let mut v = Vec::new();
+        for _ in 0..len {
+            v.push(T::decode_limbs(input)?);
+        }
+        Ok(v)
+    }
+}
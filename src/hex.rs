@@ -0,0 +1,38 @@
+//! Minimal `0x` prefixed hex for human readable forms
+
+use ark_std::{string::String, vec::Vec};
+
+const DIGITS: &[u8; 16] = b"0123456789abcdef";
+
+/// Lowercase hex of `bytes` with a `0x` prefix
+pub(crate) fn to_hex(bytes: &[u8]) -> String {
+    let mut s = String::with_capacity(2 + 2 * bytes.len());
+    s.push_str("0x");
+    for b in bytes {
+        s.push(DIGITS[(b >> 4) as usize] as char);
+        s.push(DIGITS[(b & 0xf) as usize] as char);
+    }
+    s
+}
+
+fn digit(c: u8) -> Option<u8> {
+    match c {
+        b'0'..=b'9' => Some(c - b'0'),
+        b'a'..=b'f' => Some(c - b'a' + 10),
+        b'A'..=b'F' => Some(c - b'A' + 10),
+        _ => None,
+    }
+}
+
+/// Bytes from hex with a mandatory `0x` prefix, in either case
+pub(crate) fn from_hex(s: &str) -> Result<Vec<u8>, &'static str> {
+    let s = s.strip_prefix("0x").ok_or("Hex string lacks 0x prefix")?;
+    if s.len() % 2 != 0 {
+        return Err("Hex string has odd length");
+    }
+    s.as_bytes()
+        .chunks(2)
+        .map(|c| Some(digit(c[0])? << 4 | digit(c[1])?))
+        .collect::<Option<Vec<u8>>>()
+        .ok_or("Hex string has invalid digits")
+}
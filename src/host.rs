@@ -0,0 +1,229 @@
+//! # Host call arguments and results for curve operations
+//!
+//! Runtimes offload expensive curve operations to host calls, which
+//! pass arguments and results in `HOST_CALL` usage, so neither side
+//! compresses nor validates.  We provide shared SCALE types for
+//! multi-scalar multiplication, multi-Miller loops, final
+//! exponentiations, and projective scalar multiplication, as well as
+//! a pure software host which executes them from encoded bytes.
+//!
+//! Hosts never validate their arguments, so the runtime must validate
+//! any untrusted points before making a host call.  Projective
+//! multiplication uses `ArkScaleProjective`, so everything in
+//! `hazmat` applies here too.
+
+use ark_std::vec::Vec;
+
+use ark_ec::{
+    models::{short_weierstrass as sw, twisted_edwards as te},
+    pairing::{MillerLoopOutput, Pairing, PairingOutput},
+    CurveGroup,
+};
+
+use crate::{
+    hazmat::ArkScaleProjective,
+    scale::{self, Decode, DecodeAll, Encode, EncodeLike, Input, Output},
+    ArkScale, ArkScaleRef, HOST_CALL,
+};
+
+/// Curve operation which a host performs on behalf of the runtime
+pub trait HostCall: Encode + Decode {
+    /// Result returned to the runtime
+    type Output: Encode + Decode;
+
+    /// Perform the operation natively
+    fn execute(self) -> Self::Output;
+}
+
+/// Software host which decodes a `H` from `input`, executes it,
+/// and returns the encoded result.
+///
+/// Rejects any trailing bytes in `input`.
+pub fn software_host<H: HostCall>(mut input: &[u8]) -> Result<Vec<u8>, scale::Error> {
+    let call = H::decode_all(&mut input)?;
+    Ok(call.execute().encode())
+}
+
+/// Encode `call`, execute it by `software_host`, and decode the result,
+/// exactly as a runtime would round trip through a real host.
+pub fn call_software_host<H: HostCall>(call: &H) -> Result<H::Output, scale::Error> {
+    let output = software_host::<H>(&call.encode())?;
+    H::Output::decode_all(&mut output.as_slice())
+}
+
+// Multi-scalar multiplication //
+
+/// Multi-scalar multiplication `sum_i scalars[i] * bases[i]`
+/// in any curve group, including short Weierstrass and twisted
+/// Edwards curves.
+///
+/// We reject encodings with different numbers of bases and scalars.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Msm<G: CurveGroup> {
+    pub bases: Vec<G::Affine>,
+    pub scalars: Vec<G::ScalarField>,
+}
+
+impl<G: CurveGroup> EncodeLike for Msm<G> {}
+
+impl<G: CurveGroup> Encode for Msm<G> {
+    fn size_hint(&self) -> usize {
+        ArkScaleRef::<_, HOST_CALL>(&self.bases).size_hint()
+            + ArkScaleRef::<_, HOST_CALL>(&self.scalars).size_hint()
+    }
+
+    fn encode_to<O: Output + ?Sized>(&self, dest: &mut O) {
+        ArkScaleRef::<_, HOST_CALL>(&self.bases).encode_to(dest);
+        ArkScaleRef::<_, HOST_CALL>(&self.scalars).encode_to(dest);
+    }
+}
+
+impl<G: CurveGroup> Decode for Msm<G> {
+    fn decode<I: Input>(input: &mut I) -> Result<Self, scale::Error> {
+        let bases = ArkScale::<Vec<G::Affine>, HOST_CALL>::decode(input)?.0;
+        let scalars = ArkScale::<Vec<G::ScalarField>, HOST_CALL>::decode(input)?.0;
+        if bases.len() != scalars.len() {
+            return Err("Multi-scalar multiplication has different numbers of bases and scalars".into());
+        }
+        Ok(Msm { bases, scalars })
+    }
+}
+
+impl<G: CurveGroup> HostCall for Msm<G> {
+    type Output = ArkScale<G::Affine, HOST_CALL>;
+
+    fn execute(self) -> Self::Output {
+        ArkScale(G::msm_unchecked(&self.bases, &self.scalars).into_affine())
+    }
+}
+
+// Projective multiplication //
+
+/// Projective scalar multiplication `scalar * base`, with `scalar`
+/// given as little-endian `u64` limbs, like `BigInt` or an
+/// `AsRef<[u64]>` exponent, so not necessarily reduced.
+///
+/// Implemented for short Weierstrass and twisted Edwards projective
+/// points, which the host multiplies using the curve's own
+/// `mul_projective`.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct MulProjective<T> {
+    pub base: T,
+    pub scalar: Vec<u64>,
+}
+
+macro_rules! impl_mul_projective {
+    ($m:ident, $c:path) => {
+        impl<C: $c> EncodeLike for MulProjective<$m::Projective<C>> {}
+
+        impl<C: $c> Encode for MulProjective<$m::Projective<C>> {
+            fn size_hint(&self) -> usize {
+                ArkScaleProjective(&self.base).size_hint()
+                    + ArkScaleRef::<_, HOST_CALL>(&self.scalar).size_hint()
+            }
+
+            fn encode_to<O: Output + ?Sized>(&self, dest: &mut O) {
+                ArkScaleProjective(&self.base).encode_to(dest);
+                ArkScaleRef::<_, HOST_CALL>(&self.scalar).encode_to(dest);
+            }
+        }
+
+        impl<C: $c> Decode for MulProjective<$m::Projective<C>> {
+            fn decode<I: Input>(input: &mut I) -> Result<Self, scale::Error> {
+                let base = ArkScaleProjective::<$m::Projective<C>>::decode(input)?.0;
+                let scalar = ArkScale::<Vec<u64>, HOST_CALL>::decode(input)?.0;
+                Ok(MulProjective { base, scalar })
+            }
+        }
+
+        impl<C: $c> HostCall for MulProjective<$m::Projective<C>> {
+            type Output = ArkScaleProjective<$m::Projective<C>>;
+
+            fn execute(self) -> Self::Output {
+                ArkScaleProjective(C::mul_projective(&self.base, &self.scalar))
+            }
+        }
+    };
+}
+
+impl_mul_projective!(sw, sw::SWCurveConfig);
+impl_mul_projective!(te, te::TECurveConfig);
+
+// Pairings //
+
+/// Multi-Miller loop over pairs `(a[i], b[i])`, whose output still
+/// requires a final exponentiation.
+///
+/// We reject encodings with different numbers of `G1` and `G2` points.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct MultiMillerLoop<P: Pairing> {
+    pub a: Vec<P::G1Affine>,
+    pub b: Vec<P::G2Affine>,
+}
+
+impl<P: Pairing> EncodeLike for MultiMillerLoop<P> {}
+
+impl<P: Pairing> Encode for MultiMillerLoop<P> {
+    fn size_hint(&self) -> usize {
+        ArkScaleRef::<_, HOST_CALL>(&self.a).size_hint()
+            + ArkScaleRef::<_, HOST_CALL>(&self.b).size_hint()
+    }
+
+    fn encode_to<O: Output + ?Sized>(&self, dest: &mut O) {
+        ArkScaleRef::<_, HOST_CALL>(&self.a).encode_to(dest);
+        ArkScaleRef::<_, HOST_CALL>(&self.b).encode_to(dest);
+    }
+}
+
+impl<P: Pairing> Decode for MultiMillerLoop<P> {
+    fn decode<I: Input>(input: &mut I) -> Result<Self, scale::Error> {
+        let a = ArkScale::<Vec<P::G1Affine>, HOST_CALL>::decode(input)?.0;
+        let b = ArkScale::<Vec<P::G2Affine>, HOST_CALL>::decode(input)?.0;
+        if a.len() != b.len() {
+            return Err("Multi-Miller loop has different numbers of G1 and G2 points".into());
+        }
+        Ok(MultiMillerLoop { a, b })
+    }
+}
+
+impl<P: Pairing> HostCall for MultiMillerLoop<P> {
+    type Output = ArkScale<P::TargetField, HOST_CALL>;
+
+    fn execute(self) -> Self::Output {
+        ArkScale(P::multi_miller_loop(self.a, self.b).0)
+    }
+}
+
+/// Final exponentiation of a multi-Miller loop output `f`, which
+/// yields `None` only if `f` is zero.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct FinalExponentiation<P: Pairing> {
+    pub f: P::TargetField,
+}
+
+impl<P: Pairing> EncodeLike for FinalExponentiation<P> {}
+
+impl<P: Pairing> Encode for FinalExponentiation<P> {
+    fn size_hint(&self) -> usize {
+        ArkScaleRef::<_, HOST_CALL>(&self.f).size_hint()
+    }
+
+    fn encode_to<O: Output + ?Sized>(&self, dest: &mut O) {
+        ArkScaleRef::<_, HOST_CALL>(&self.f).encode_to(dest);
+    }
+}
+
+impl<P: Pairing> Decode for FinalExponentiation<P> {
+    fn decode<I: Input>(input: &mut I) -> Result<Self, scale::Error> {
+        let f = ArkScale::<P::TargetField, HOST_CALL>::decode(input)?.0;
+        Ok(FinalExponentiation { f })
+    }
+}
+
+impl<P: Pairing> HostCall for FinalExponentiation<P> {
+    type Output = Option<ArkScale<PairingOutput<P>, HOST_CALL>>;
+
+    fn execute(self) -> Self::Output {
+        P::final_exponentiation(MillerLoopOutput(self.f)).map(ArkScale)
+    }
+}
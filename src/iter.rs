@@ -0,0 +1,224 @@
+//! # Sequences without materialization
+//!
+//! Arkworks encodes `Vec<T>` as a `u64` little-endian length prefix
+//! followed by its elements, which `iter_ark_to_ark_bytes` produces from
+//! any iterator.  We encode such sequences directly into SCALE `Output`s
+//! and decode them lazily here, so verifiers could fold over large point
+//! sets in constant memory.  Fixed-size elements also permit random
+//! access into the encoding, via `ArkScaleSliceView`.
+
+use ark_std::{borrow::Borrow, iter::FusedIterator, marker::PhantomData, vec::Vec};
+
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+
+use crate::{
+    is_compressed,
+    rw::OutputAsWrite,
+    scale::{self, Decode, Encode, EncodeLike, Input, Output},
+    ArkScale, ArkScaleMaxEncodedLen, Usage, OOPS, WIRE,
+};
+
+/// Encode `iter` into `dest` exactly like `ArkScale<Vec<T>, U>` with
+/// `usage` as `U`, but without any intermediate buffer.
+///
+/// We write the length prefix first, so we panic if `iter` yields more
+/// or fewer elements than `ExactSizeIterator::len` claimed, in the
+/// former case before serializing the first extra element.
+pub fn encode_iter_to<T, B, I, O>(iter: I, usage: Usage, dest: &mut O)
+where
+    T: CanonicalSerialize,
+    B: Borrow<T>,
+    I: IntoIterator<Item = B>,
+    I::IntoIter: ExactSizeIterator,
+    O: Output + ?Sized,
+{
+    let iter = iter.into_iter();
+    let len = iter.len();
+    (len as u64).encode_to(dest);
+    let mut l = 0;
+    for e in iter {
+        assert!(l < len, "ExactSizeIterator::len was wrong, so encode_iter_to wrote a bad length prefix");
+        e.borrow()
+            .serialize_with_mode(OutputAsWrite(dest), is_compressed(usage))
+            .expect(OOPS);
+        l += 1;
+    }
+    assert_eq!(l, len, "ExactSizeIterator::len was wrong, so encode_iter_to wrote a bad length prefix");
+}
+
+/// Iterator wrapped for encoding by SCALE exactly like `ArkScale<Vec<T>, U>`,
+/// so slices and adapters need not be collected first.
+///
+/// We encode from a clone of the iterator, so use cheap `Clone`s like
+/// `&[T]` or `slice::Iter`.  We need `T: ArkScaleMaxEncodedLen` for
+/// `size_hint` without evaluating elements, so use `encode_iter_to`
+/// directly for other `T`.
+#[derive(Clone, Debug)]
+pub struct ArkScaleIter<T, I, const U: Usage = WIRE>(pub I, PhantomData<fn() -> T>);
+
+impl<T, I, const U: Usage> ArkScaleIter<T, I, U> {
+    pub fn new(iter: I) -> Self {
+        ArkScaleIter(iter, PhantomData)
+    }
+}
+
+impl<T, B, I, const U: Usage> Encode for ArkScaleIter<T, I, U>
+where
+    T: CanonicalSerialize + ArkScaleMaxEncodedLen,
+    B: Borrow<T>,
+    I: IntoIterator<Item = B> + Clone,
+    I::IntoIter: ExactSizeIterator,
+{
+    fn size_hint(&self) -> usize {
+        8 + self.0.clone().into_iter().len() * T::max_encoded_len(is_compressed(U))
+    }
+
+    fn encode_to<O: Output + ?Sized>(&self, dest: &mut O) {
+        encode_iter_to::<T, B, I, O>(self.0.clone(), U, dest)
+    }
+}
+
+impl<T, B, I, const U: Usage> EncodeLike for ArkScaleIter<T, I, U>
+where
+    T: CanonicalSerialize + ArkScaleMaxEncodedLen,
+    B: Borrow<T>,
+    I: IntoIterator<Item = B> + Clone,
+    I::IntoIter: ExactSizeIterator,
+{
+}
+
+impl<T, B, I, const U: Usage> EncodeLike<ArkScale<Vec<T>, U>> for ArkScaleIter<T, I, U>
+where
+    T: CanonicalSerialize + ArkScaleMaxEncodedLen,
+    B: Borrow<T>,
+    I: IntoIterator<Item = B> + Clone,
+    I::IntoIter: ExactSizeIterator,
+{
+}
+
+/// Lazily decodes an arkworks `Vec<T>` encoding from a SCALE `Input`,
+/// yielding each element exactly like `ArkScale<T, U>::decode`.
+///
+/// We stop after the first error, leaving `input` wherever that
+/// element's decoding failed.
+pub struct ArkScaleSeqIter<'a, I: Input, T, const U: Usage = WIRE> {
+    input: &'a mut I,
+    remaining: u64,
+    _t: PhantomData<fn() -> T>,
+}
+
+impl<'a, I: Input, T, const U: Usage> ArkScaleSeqIter<'a, I, T, U> {
+    /// Read the length prefix from `input`.
+    ///
+    /// We never allocate by the length prefix, so we need not check it
+    /// against `remaining_len`, which zero sized elements like `()`
+    /// could exceed anyway.  Truncated input fails once the decoding
+    /// of some element runs out.
+    pub fn new(input: &'a mut I) -> Result<Self, scale::Error> {
+        let remaining = u64::decode(input)?;
+        Ok(ArkScaleSeqIter { input, remaining, _t: PhantomData })
+    }
+
+    /// Number of elements not yet decoded, or zero after an error.
+    pub fn remaining(&self) -> u64 {
+        self.remaining
+    }
+}
+
+impl<'a, I: Input, T: CanonicalDeserialize, const U: Usage> Iterator for ArkScaleSeqIter<'a, I, T, U> {
+    type Item = Result<T, scale::Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        match ArkScale::<T, U>::decode(self.input) {
+            Ok(t) => {
+                self.remaining -= 1;
+                Some(Ok(t.0))
+            }
+            Err(e) => {
+                self.remaining = 0;
+                Some(Err(e))
+            }
+        }
+    }
+
+    /// Errors end iteration early, so only one item is certain.
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let upper = usize::try_from(self.remaining).ok();
+        (self.remaining.min(1) as usize, upper)
+    }
+}
+
+impl<'a, I: Input, T: CanonicalDeserialize, const U: Usage> FusedIterator for ArkScaleSeqIter<'a, I, T, U> {}
+
+/// Random access view into the encoding of `ArkScale<Vec<T>, U>`,
+/// whose elements all have size `T::max_encoded_len`, like curve points
+/// and field elements in any usage.
+///
+/// We check the length prefix against the byte length upfront, but
+/// decode and validate only the elements requested.
+pub struct ArkScaleSliceView<'a, T, const U: Usage = WIRE> {
+    bytes: &'a [u8],
+    len: usize,
+    _t: PhantomData<fn() -> T>,
+}
+
+impl<'a, T, const U: Usage> Clone for ArkScaleSliceView<'a, T, U> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, T, const U: Usage> Copy for ArkScaleSliceView<'a, T, U> {}
+
+impl<'a, T, const U: Usage> ArkScaleSliceView<'a, T, U>
+where
+    T: 'a + CanonicalDeserialize + ArkScaleMaxEncodedLen,
+{
+    /// Size of each element
+    pub fn stride() -> usize {
+        T::max_encoded_len(is_compressed(U))
+    }
+
+    /// View `bytes`, which must be exactly one `ArkScale<Vec<T>, U>`.
+    pub fn new(bytes: &'a [u8]) -> Result<Self, scale::Error> {
+        let mut input = bytes;
+        let len = usize::try_from(u64::decode(&mut input)?)
+            .map_err(|_| "ArkScaleSliceView length prefix overflows usize")?;
+        if len.checked_mul(Self::stride()) != Some(input.len()) {
+            return Err("ArkScaleSliceView length prefix disagrees with byte length".into());
+        }
+        Ok(ArkScaleSliceView { bytes: input, len, _t: PhantomData })
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Decode element `i`, or `None` if out of bounds.
+    pub fn get(&self, i: usize) -> Option<Result<T, scale::Error>> {
+        (i < self.len).then(|| self.decode_at(i))
+    }
+
+    /// Decode elements in order, each upon demand.
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = Result<T, scale::Error>> + ExactSizeIterator + 'a {
+        let view = *self;
+        (0..self.len).map(move |i| view.decode_at(i))
+    }
+
+    fn decode_at(&self, i: usize) -> Result<T, scale::Error> {
+        let stride = Self::stride();
+        let mut input = &self.bytes[i * stride..(i + 1) * stride];
+        let t = ArkScale::<T, U>::decode(&mut input)?;
+        if !input.is_empty() {
+            return Err("ArkScaleSliceView element shorter than its max_encoded_len".into());
+        }
+        Ok(t.0)
+    }
+}
@@ -0,0 +1,159 @@
+//! # Lazy decoding
+//!
+//! Runtime storage and call types often carry points which most code
+//! paths never touch.  `ArkScaleLazy<T, U>` encodes exactly like
+//! `ArkScale<T, U>`, but decoding only captures its bytes, found by
+//! `ArkScale::skip`, and defers the real decoding and validation to
+//! the first `get`.
+//!
+//! We cache the decoded value in a `once_cell::race::OnceBox`, so
+//! `ArkScaleLazy<T, U>` is `Sync` whenever `T` is `Send + Sync`.
+//! Racing threads may each decode, but only one result is kept.
+
+use ark_std::{boxed::Box, vec::Vec};
+
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, Validate};
+use once_cell::race::OnceBox;
+use scale_info::TypeInfo;
+
+use crate::{
+    scale::{self, Decode, Encode, EncodeLike, Input, MaxEncodedLen, Output},
+    is_compressed, rw::ark_error_to_scale_error, ArkScale, ArkScaleMaxEncodedLen, ArkScaleRef, Usage, WIRE,
+};
+
+/// Arkworks type whose SCALE encoding we keep, but decode only upon use.
+///
+/// We re-encode the original bytes unchanged, even if they never decode.
+#[derive(Clone, Debug)]
+pub struct ArkScaleLazy<T, const U: Usage = WIRE> {
+    bytes: Vec<u8>,
+    value: OnceBox<T>,
+}
+
+impl<T: CanonicalSerialize, const U: Usage> From<T> for ArkScaleLazy<T, U> {
+    fn from(t: T) -> ArkScaleLazy<T, U> {
+        let bytes = ArkScaleRef::<T, U>(&t).encode();
+        ArkScaleLazy { bytes, value: OnceBox::with_value(Box::new(t)) }
+    }
+}
+
+impl<T, const U: Usage> ArkScaleLazy<T, U> {
+    /// Our encoding, exactly as decoded or encoded.
+    pub fn encoded(&self) -> &[u8] {
+        &self.bytes
+    }
+}
+
+impl<T: CanonicalDeserialize, const U: Usage> ArkScaleLazy<T, U> {
+    /// Decode and validate upon first use, and cache only successes.
+    pub fn get(&self) -> Result<&T, scale::Error> {
+        self.value
+            .get_or_try_init(|| ArkScale::<T, U>::decode(&mut self.bytes.as_slice()).map(|a| Box::new(a.0)))
+    }
+
+    /// `OnceBox` cannot give up its value, so we decode once more, but
+    /// skip validation if our bytes were already validated or encoded.
+    pub fn into_inner(self) -> Result<T, scale::Error> {
+        if self.value.get().is_none() {
+            return ArkScale::<T, U>::decode(&mut self.bytes.as_slice()).map(|a| a.0);
+        }
+        T::deserialize_with_mode(self.bytes.as_slice(), is_compressed(U), Validate::No).map_err(ark_error_to_scale_error)
+    }
+}
+
+/// Equality of encodings, which avoids decoding.
+impl<T, const U: Usage> PartialEq for ArkScaleLazy<T, U> {
+    fn eq(&self, other: &Self) -> bool {
+        self.bytes == other.bytes
+    }
+}
+
+impl<T, const U: Usage> Eq for ArkScaleLazy<T, U> {}
+
+/// Copies everything `inner` reads into `bytes`.
+struct RecordingInput<'a, I: Input> {
+    inner: &'a mut I,
+    bytes: Vec<u8>,
+}
+
+impl<'a, I: Input> Input for RecordingInput<'a, I> {
+    fn remaining_len(&mut self) -> Result<Option<usize>, scale::Error> {
+        self.inner.remaining_len()
+    }
+
+    fn read(&mut self, into: &mut [u8]) -> Result<(), scale::Error> {
+        self.inner.read(into)?;
+        self.bytes.extend_from_slice(into);
+        Ok(())
+    }
+
+    fn descend_ref(&mut self) -> Result<(), scale::Error> {
+        self.inner.descend_ref()
+    }
+
+    fn ascend_ref(&mut self) {
+        self.inner.ascend_ref()
+    }
+
+    fn on_before_alloc_mem(&mut self, size: usize) -> Result<(), scale::Error> {
+        self.inner.on_before_alloc_mem(size)
+    }
+}
+
+impl<T: CanonicalDeserialize, const U: Usage> Decode for ArkScaleLazy<T, U> {
+    fn decode<I: Input>(input: &mut I) -> Result<Self, scale::Error> {
+        let mut recording = RecordingInput { inner: input, bytes: Vec::new() };
+        ArkScale::<T, U>::skip(&mut recording)?;
+        Ok(ArkScaleLazy { bytes: recording.bytes, value: OnceBox::new() })
+    }
+
+    fn skip<I: Input>(input: &mut I) -> Result<(), scale::Error> {
+        ArkScale::<T, U>::skip(input)
+    }
+
+    fn encoded_fixed_size() -> Option<usize> {
+        ArkScale::<T, U>::encoded_fixed_size()
+    }
+}
+
+impl<T, const U: Usage> Encode for ArkScaleLazy<T, U> {
+    fn size_hint(&self) -> usize {
+        self.bytes.len()
+    }
+
+    fn encode_to<O: Output + ?Sized>(&self, dest: &mut O) {
+        dest.write(&self.bytes)
+    }
+
+    fn using_encoded<R, F: FnOnce(&[u8]) -> R>(&self, f: F) -> R {
+        f(&self.bytes)
+    }
+
+    fn encoded_size(&self) -> usize {
+        self.bytes.len()
+    }
+}
+
+impl<T, const U: Usage> EncodeLike for ArkScaleLazy<T, U> {}
+
+impl<T: CanonicalSerialize, const U: Usage> EncodeLike<ArkScale<T, U>> for ArkScaleLazy<T, U> {}
+
+impl<T: CanonicalSerialize, const U: Usage> EncodeLike<ArkScaleLazy<T, U>> for ArkScale<T, U> {}
+
+impl<T, const U: Usage> MaxEncodedLen for ArkScaleLazy<T, U>
+where
+    T: CanonicalSerialize + ArkScaleMaxEncodedLen,
+{
+    #[inline]
+    fn max_encoded_len() -> usize {
+        <ArkScale<T, U> as MaxEncodedLen>::max_encoded_len()
+    }
+}
+
+impl<T: 'static + ArkScaleMaxEncodedLen, const U: Usage> TypeInfo for ArkScaleLazy<T, U> {
+    type Identity = Self;
+
+    fn type_info() -> scale_info::Type {
+        <ArkScale<T, U> as TypeInfo>::type_info()
+    }
+}
@@ -33,6 +33,12 @@ use rw::*;
 mod max_encoded_len;
 pub use max_encoded_len::*;
 
+mod iter;
+pub use iter::*;
+
+mod lazy;
+pub use lazy::ArkScaleLazy;
+
 #[cfg(feature = "hazmat")]
 pub mod hazmat;
 
@@ -42,6 +48,22 @@ pub mod ff;
 #[cfg(feature = "ec")]
 pub mod ec;
 
+#[cfg(feature = "hazmat")]
+pub mod host;
+
+mod hex;
+
+mod traits;
+
+#[cfg(feature = "serde")]
+pub mod serde;
+
+#[cfg(feature = "borsh")]
+pub mod borsh;
+
+#[cfg(feature = "dynamic")]
+pub mod dynamic;
+
 #[cfg(test)]
 mod tests;
 
@@ -118,12 +140,21 @@ impl<T: CanonicalDeserialize, const U: Usage> Decode for ArkScale<T, U> {
         .map_err(ark_error_to_scale_error)
     }
 
-    // fn skip<I: Input>(input: &mut I) -> Result<(), Error> { ... }
+    /// Skip by decoding without validation, which reads identically.
+    fn skip<I: Input>(input: &mut I) -> Result<(), scale::Error> {
+        <T as CanonicalDeserialize>::deserialize_with_mode(
+            InputAsRead(input),
+            is_compressed(U),
+            Validate::No,
+        )
+        .map(|_| ())
+        .map_err(ark_error_to_scale_error)
+    }
 
     // fn encoded_fixed_size() -> Option<usize> { ... }
 }
 
-const OOPS: &'static str =
+pub(crate) const OOPS: &str =
     "Arkworks serialization failed, but Scale cannot handle serialization failures.  As ark_scale::rw::OutputAsWrite cannot fail, and ark_serialize_derive cannot introduce fresh falures, you have a non-derived `impl<..> ark_serialize::CanonicalSerialize` which fails, which violates usage conditions from ark-scale/README.md.";
     // You could usually verify this condition by reading results like
     // git clone https://github.com/arkworks-rs/algebra
@@ -167,7 +198,6 @@ impl<T: 'static + ArkScaleMaxEncodedLen, const U: Usage> TypeInfo for ArkScale<T
     }
 }
 
-
 #[derive(Copy,Debug)] // CanonicalSerialize
 pub struct ArkScaleRef<'a, T, const U: Usage = WIRE>(pub &'a T);
 
@@ -203,6 +233,32 @@ impl<'a, T: CanonicalSerialize, const U: Usage> Encode for ArkScaleRef<'a, T, U>
     }
 }
 
+/// Encode bare arkworks fields via `#[codec(encoded_as = "ArkScale<T, U>")]`
+impl<'a, T: 'a + CanonicalSerialize, const U: Usage> scale::EncodeAsRef<'a, T> for ArkScale<T, U> {
+    type RefType = ArkScaleRef<'a, T, U>;
+}
+
+impl<'a, T: 'a + CanonicalSerialize, const U: Usage> scale::EncodeAsRef<'a, T> for ArkScaleRef<'a, T, U> {
+    type RefType = ArkScaleRef<'a, T, U>;
+}
+
+/// Implement `From<ArkScale<T, U>> for T`, which decoding bare fields
+/// via `#[codec(encoded_as = "ArkScale<T, U>")]` requires.  The orphan
+/// rules forbid this for generic `T`, but permit it for each arkworks
+/// type constructor.
+macro_rules! impl_from_ark_scale {
+    ($t:ty; $($g:tt)*) => {
+        impl<$($g)*, const U: $crate::Usage> From<$crate::ArkScale<$t, U>> for $t {
+            fn from(t: $crate::ArkScale<$t, U>) -> $t {
+                t.0
+            }
+        }
+    };
+}
+#[cfg(feature = "ff")]
+pub(crate) use impl_from_ark_scale;
+
+impl_from_ark_scale!(Vec<T>; T);
 
 /// Arkworks' `CanonicalSerialize` cannot consume `Iterator`s directly,
 /// but `iter_ark_to_ark_bytes` serializes exactly like `Vec<T>`,
@@ -262,6 +318,50 @@ where
 }
 
 
+/// Implement `Encode`, `EncodeLike`, `MaxEncodedLen`, and `TypeInfo`
+/// for a decoding wrapper by delegation to `ArkScale`.
+#[cfg(feature = "ff")]
+macro_rules! impl_forward_to_ark_scale {
+    ($w:ident) => {
+        impl<T: ark_serialize::CanonicalSerialize, const U: $crate::Usage> $crate::scale::EncodeLike for $w<T, U> {}
+
+        impl<T: ark_serialize::CanonicalSerialize, const U: $crate::Usage> $crate::scale::Encode for $w<T, U> {
+            fn size_hint(&self) -> usize {
+                $crate::ArkScaleRef::<T, U>(&self.0).size_hint()
+            }
+
+            fn encode_to<O: $crate::scale::Output + ?Sized>(&self, dest: &mut O) {
+                $crate::ArkScaleRef::<T, U>(&self.0).encode_to(dest)
+            }
+
+            fn encoded_size(&self) -> usize {
+                $crate::ArkScaleRef::<T, U>(&self.0).encoded_size()
+            }
+        }
+
+        impl<T, const U: $crate::Usage> $crate::scale::MaxEncodedLen for $w<T, U>
+        where
+            T: ark_serialize::CanonicalSerialize + $crate::ArkScaleMaxEncodedLen,
+        {
+            #[inline]
+            fn max_encoded_len() -> usize {
+                <$crate::ArkScale<T, U> as $crate::scale::MaxEncodedLen>::max_encoded_len()
+            }
+        }
+
+        impl<T: 'static + $crate::ArkScaleMaxEncodedLen, const U: $crate::Usage> scale_info::TypeInfo for $w<T, U> {
+            type Identity = Self;
+
+            fn type_info() -> scale_info::Type {
+                <$crate::ArkScale<T, U> as scale_info::TypeInfo>::type_info()
+            }
+        }
+    };
+}
+#[cfg(feature = "ff")]
+pub(crate) use impl_forward_to_ark_scale;
+
+
 // We next provide helper macros for implementing scale upon
 // your own arkworks types.
 
@@ -83,3 +83,35 @@ impl<'a, I: Output + ?Sized> Write for OutputAsWrite<'a, I> {
         Ok(())
     }
 }
+
+/// Streaming through `std::io`, for off-chain tools which read large
+/// SRS or proof files, without buffering the whole encoding.
+#[cfg(feature = "std")]
+impl<T: CanonicalDeserialize, const U: Usage> ArkScale<T, U> {
+    /// Decode from a `std::io::Read`, exactly like `Decode::decode`.
+    pub fn decode_from_reader<R: std::io::Read>(reader: &mut R) -> Result<Self, scale::Error> {
+        <T as CanonicalDeserialize>::deserialize_with_mode(reader, is_compressed(U), is_validated(U))
+            .map(ArkScale)
+            .map_err(ark_error_to_scale_error)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: CanonicalSerialize, const U: Usage> ArkScale<T, U> {
+    /// Encode to a `std::io::Write`, exactly like `Encode::encode_to`,
+    /// except returning any I/O errors.
+    pub fn encode_to_writer<W: std::io::Write>(&self, writer: &mut W) -> Result<(), scale::Error> {
+        ArkScaleRef::<T, U>(&self.0).encode_to_writer(writer)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'a, T: CanonicalSerialize, const U: Usage> ArkScaleRef<'a, T, U> {
+    /// Encode to a `std::io::Write`, exactly like `Encode::encode_to`,
+    /// except returning any I/O errors.
+    pub fn encode_to_writer<W: std::io::Write>(&self, writer: &mut W) -> Result<(), scale::Error> {
+        self.0
+            .serialize_with_mode(writer, is_compressed(U))
+            .map_err(ark_error_to_scale_error)
+    }
+}
@@ -0,0 +1,132 @@
+//! # Serde integration
+//!
+//! `ArkScale<T, U>` and `ArkScaleRef<T, U>` serialize the same arkworks
+//! bytes as their SCALE encoding, as `0x` prefixed hex in human readable
+//! formats like JSON, and as raw bytes in binary formats like bincode or
+//! postcard.  We respect `U` for compression and validation, and report
+//! validation failures as serde errors.
+//!
+//! Bare arkworks fields could use `#[serde(with = "ark_scale::serde::wire")]`
+//! or `#[serde(with = "ark_scale::serde::host_call")]` instead, or
+//! `serialize_with_usage` and `deserialize_with_usage` for other usages.
+
+use ark_std::{fmt, vec::Vec};
+
+use ::serde::{de, ser, Deserialize, Deserializer, Serialize, Serializer};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+
+use crate::{hex, is_compressed, is_validated, ArkScale, ArkScaleRef, Usage};
+
+/// Serialize `t` in usage `U`, as hex if human readable or else bytes.
+pub fn serialize_with_usage<T, S, const U: Usage>(t: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: CanonicalSerialize,
+    S: Serializer,
+{
+    let mut bytes = Vec::with_capacity(t.serialized_size(is_compressed(U)));
+    t.serialize_with_mode(&mut bytes, is_compressed(U))
+        .map_err(ser::Error::custom)?;
+    if serializer.is_human_readable() {
+        serializer.serialize_str(&hex::to_hex(&bytes))
+    } else {
+        serializer.serialize_bytes(&bytes)
+    }
+}
+
+/// Deserialize in usage `U`, from hex if human readable or else bytes,
+/// rejecting trailing bytes.
+pub fn deserialize_with_usage<'de, T, D, const U: Usage>(deserializer: D) -> Result<T, D::Error>
+where
+    T: CanonicalDeserialize,
+    D: Deserializer<'de>,
+{
+    let bytes = if deserializer.is_human_readable() {
+        deserializer.deserialize_str(BytesVisitor)?
+    } else {
+        deserializer.deserialize_bytes(BytesVisitor)?
+    };
+    let mut reader = bytes.as_slice();
+    let t = T::deserialize_with_mode(&mut reader, is_compressed(U), is_validated(U))
+        .map_err(de::Error::custom)?;
+    if !reader.is_empty() {
+        return Err(de::Error::custom("Arkworks deserialization left trailing bytes"));
+    }
+    Ok(t)
+}
+
+struct BytesVisitor;
+
+impl<'de> de::Visitor<'de> for BytesVisitor {
+    type Value = Vec<u8>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("0x prefixed hex or bytes of an arkworks serialization")
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Vec<u8>, E> {
+        hex::from_hex(v).map_err(E::custom)
+    }
+
+    fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<Vec<u8>, E> {
+        Ok(v.to_vec())
+    }
+
+    fn visit_byte_buf<E: de::Error>(self, v: Vec<u8>) -> Result<Vec<u8>, E> {
+        Ok(v)
+    }
+
+    // Some binary formats represent bytes as sequences.
+    fn visit_seq<A: de::SeqAccess<'de>>(self, mut seq: A) -> Result<Vec<u8>, A::Error> {
+        let mut v = Vec::new();
+        while let Some(b) = seq.next_element()? {
+            v.push(b);
+        }
+        Ok(v)
+    }
+}
+
+impl<T: CanonicalSerialize, const U: Usage> Serialize for ArkScale<T, U> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_with_usage::<T, S, U>(&self.0, serializer)
+    }
+}
+
+impl<'a, T: CanonicalSerialize, const U: Usage> Serialize for ArkScaleRef<'a, T, U> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_with_usage::<T, S, U>(self.0, serializer)
+    }
+}
+
+impl<'de, T: CanonicalDeserialize, const U: Usage> Deserialize<'de> for ArkScale<T, U> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize_with_usage::<T, D, U>(deserializer).map(ArkScale)
+    }
+}
+
+/// Serde helpers for bare arkworks fields in `WIRE` usage
+pub mod wire {
+    use super::*;
+    use crate::WIRE;
+
+    pub fn serialize<T: CanonicalSerialize, S: Serializer>(t: &T, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_with_usage::<T, S, WIRE>(t, serializer)
+    }
+
+    pub fn deserialize<'de, T: CanonicalDeserialize, D: Deserializer<'de>>(deserializer: D) -> Result<T, D::Error> {
+        deserialize_with_usage::<T, D, WIRE>(deserializer)
+    }
+}
+
+/// Serde helpers for bare arkworks fields in `HOST_CALL` usage
+pub mod host_call {
+    use super::*;
+    use crate::HOST_CALL;
+
+    pub fn serialize<T: CanonicalSerialize, S: Serializer>(t: &T, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_with_usage::<T, S, HOST_CALL>(t, serializer)
+    }
+
+    pub fn deserialize<'de, T: CanonicalDeserialize, D: Deserializer<'de>>(deserializer: D) -> Result<T, D::Error> {
+        deserialize_with_usage::<T, D, HOST_CALL>(deserializer)
+    }
+}
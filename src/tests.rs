@@ -118,3 +118,1263 @@ fn macros() {
     let b: [MacroTest; 4] = <[MacroTest; 4] as Decode>::decode(&mut v.as_slice()).unwrap();
     assert_eq!(a,b);
 }
+
+#[cfg(feature = "ec")]
+#[test]
+fn clear_cofactor() {
+    use ark_ec::{twisted_edwards as te, AffineRepr};
+    use ark_ed25519::{EdwardsAffine, Fq};
+    use crate::ec::{ClearCofactor, TorsionFree};
+
+    // Find some on-curve point with a non-trivial torsion component.
+    let torsion = loop {
+        let y = <Fq as UniformRand>::rand(&mut rand_core::OsRng);
+        if let Some(p) = EdwardsAffine::get_point_from_y_unchecked(y, false) {
+            if !p.is_in_correct_subgroup_assuming_on_curve() {
+                break p;
+            }
+        }
+    };
+    let good = <EdwardsAffine as UniformRand>::rand(&mut rand_core::OsRng);
+
+    for u in [WIRE, HOST_CALL] {
+        let enc = |p: &EdwardsAffine| {
+            let mut v = Vec::new();
+            p.serialize_with_mode(&mut v, is_compressed(u)).unwrap();
+            v
+        };
+        let t = enc(&torsion);
+        let g = enc(&good);
+        if u == WIRE {
+            assert!(<ArkScale<EdwardsAffine, WIRE>>::decode(&mut t.as_slice()).is_err());
+            let c = <ClearCofactor<EdwardsAffine, WIRE>>::decode(&mut t.as_slice()).unwrap();
+            assert_eq!(c.0, torsion.clear_cofactor());
+            assert!(c.0.is_in_correct_subgroup_assuming_on_curve());
+            assert!(<TorsionFree<EdwardsAffine, WIRE>>::decode(&mut t.as_slice()).is_err());
+            // Points of prime order change too, since we multiply by h.
+            let c = <ClearCofactor<EdwardsAffine, WIRE>>::decode(&mut g.as_slice()).unwrap();
+            assert_eq!(c.0, good.mul_by_cofactor());
+            assert_ne!(c.0, good);
+            let f = <TorsionFree<EdwardsAffine, WIRE>>::decode(&mut g.as_slice()).unwrap();
+            assert_eq!(f.0, good);
+            assert_eq!(f.encode(), g);
+        } else {
+            let c = <ClearCofactor<EdwardsAffine, HOST_CALL>>::decode(&mut t.as_slice()).unwrap();
+            assert_eq!(c.0, torsion.clear_cofactor());
+            assert!(<TorsionFree<EdwardsAffine, HOST_CALL>>::decode(&mut t.as_slice()).is_err());
+            let off = te::Affine::new_unchecked(torsion.x, torsion.x);
+            assert!(<ClearCofactor<EdwardsAffine, HOST_CALL>>::decode(&mut enc(&off).as_slice()).is_err());
+            assert_eq!(
+                <ClearCofactor<EdwardsAffine, HOST_CALL> as MaxEncodedLen>::max_encoded_len(),
+                64
+            );
+        }
+    }
+}
+
+#[cfg(feature = "ec")]
+#[test]
+fn non_degenerate() {
+    use ark_ec::AffineRepr;
+    use ark_std::{One, Zero};
+    use crate::{ec::NonIdentity, ff::NonZero};
+    type G1 = ark_bls12_381::G1Affine;
+    type Fr = ark_bls12_381::Fr;
+
+    let zero = ArkScale::<Fr, WIRE>(Fr::zero()).encode();
+    let one = ArkScale::<Fr, WIRE>(Fr::one()).encode();
+    assert!(<NonZero<Fr>>::decode(&mut zero.as_slice()).is_err());
+    assert_eq!(<NonZero<Fr>>::decode(&mut one.as_slice()).unwrap().0, Fr::one());
+    assert_eq!(NonZero::<Fr>(Fr::one()).encode(), one);
+
+    let id = ArkScale::<G1, HOST_CALL>(G1::zero()).encode();
+    let g = ArkScale::<G1, HOST_CALL>(G1::generator()).encode();
+    assert!(<NonIdentity<G1, HOST_CALL>>::decode(&mut id.as_slice()).is_err());
+    let d = <NonIdentity<G1, HOST_CALL>>::decode(&mut g.as_slice()).unwrap();
+    assert_eq!(d.0, G1::generator());
+    assert_eq!(d.encode(), g);
+    assert_eq!(
+        <NonIdentity<G1, HOST_CALL> as MaxEncodedLen>::max_encoded_len(),
+        <ArkScale<G1, HOST_CALL> as MaxEncodedLen>::max_encoded_len()
+    );
+    assert_eq!(<NonZero<Fr> as MaxEncodedLen>::max_encoded_len(), 32);
+}
+
+#[cfg(feature = "ec")]
+#[test]
+fn eip2537() {
+    use ark_ec::AffineRepr;
+    use ark_std::{format, vec, One};
+    use crate::{ec::Eip2537, hex::from_hex};
+    type G1 = ark_bls12_381::G1Affine;
+    type G2 = ark_bls12_381::G2Affine;
+    type Fr = ark_bls12_381::Fr;
+
+    let pad = |s: &str| format!("{:0>128}", s);
+    let g1 = from_hex(&[
+        "0x".into(),
+        pad("17f1d3a73197d7942695638c4fa9ac0fc3688c4f9774b905a14e3a3f171bac586c55e83ff97a1aeffb3af00adb22c6bb"),
+        pad("08b3f481e3aaa0f1a09e30ed741d8ae4fcf5e095d5d00af600db18cb2c04b3edd03cc744a2888ae40caa232946c5e7e1"),
+    ].concat()).unwrap();
+    let g2 = from_hex(&[
+        "0x".into(),
+        pad("024aa2b2f08f0a91260805272dc51051c6e47ad4fa403b02b4510b647ae3d1770bac0326a805bbefd48056c8c121bdb8"),
+        pad("13e02b6052719f607dacd3a088274f65596bd0d09920b61ab5da61bbdc7f5049334cf11213945d57e5ac7d055d042b7e"),
+        pad("0ce5d527727d6e118cc9cdc6da2e351aadfd9baa8cbdd3a76d429a695160d12c923ac9cc3baca289e193548608b82801"),
+        pad("0606c4a02ea734cc32acd2b02bc28b99cb3e287e85a763af267492ab572e99ab3f370d275cec1da1aaa9075ff05f79be"),
+    ].concat()).unwrap();
+    assert_eq!(Eip2537(G1::generator()).encode(), g1);
+    assert_eq!(Eip2537(G2::generator()).encode(), g2);
+    assert_eq!(<Eip2537<G1>>::decode(&mut g1.as_slice()).unwrap().0, G1::generator());
+    assert_eq!(<Eip2537<G2>>::decode(&mut g2.as_slice()).unwrap().0, G2::generator());
+    assert_eq!(<Eip2537<G1> as MaxEncodedLen>::max_encoded_len(), 128);
+    assert_eq!(<Eip2537<G2> as MaxEncodedLen>::max_encoded_len(), 256);
+
+    let id = Eip2537(G1::zero()).encode();
+    assert_eq!(id, vec![0u8; 128]);
+    assert!(<Eip2537<G1>>::decode(&mut id.as_slice()).unwrap().0.is_zero());
+
+    // Flag bits or padding are rejected, as are points off the curve.
+    let mut bad = g1.clone();
+    bad[0] = 0x80;
+    assert!(<Eip2537<G1>>::decode(&mut bad.as_slice()).is_err());
+    let mut bad = g1.clone();
+    bad[127] ^= 1;
+    assert!(<Eip2537<G1>>::decode(&mut bad.as_slice()).is_err());
+
+    for _ in 0..4 {
+        let p = <G2 as UniformRand>::rand(&mut rand_core::OsRng);
+        let e = Eip2537(p).encode();
+        assert_eq!(<Eip2537<G2>>::decode(&mut e.as_slice()).unwrap().0, p);
+    }
+
+    let one = Eip2537(Fr::one()).encode();
+    assert_eq!(one.len(), 32);
+    assert_eq!(one[31], 1);
+    assert_eq!(<Eip2537<Fr>>::decode(&mut one.as_slice()).unwrap().0, Fr::one());
+    assert!(<Eip2537<Fr>>::decode(&mut [0xffu8; 32].as_slice()).is_err());
+}
+
+#[cfg(feature = "ec")]
+#[test]
+fn zcash() {
+    use ark_ec::AffineRepr;
+    use ark_ff::PrimeField;
+    use ark_std::{format, vec};
+    use crate::ec::Zcash;
+    use crate::hex::from_hex;
+    type G1 = ark_bls12_381::G1Affine;
+    type G2 = ark_bls12_381::G2Affine;
+
+    // Generators and identities from the zkcrypto/bls12_381 test suite
+    let g1 = from_hex("0x97f1d3a73197d7942695638c4fa9ac0fc3688c4f9774b905a14e3a3f171bac586c55e83ff97a1aeffb3af00adb22c6bb").unwrap();
+    let g2 = from_hex("0x93e02b6052719f607dacd3a088274f65596bd0d09920b61ab5da61bbdc7f5049334cf11213945d57e5ac7d055d042b7e024aa2b2f08f0a91260805272dc51051c6e47ad4fa403b02b4510b647ae3d1770bac0326a805bbefd48056c8c121bdb8").unwrap();
+    let g1_u = from_hex("0x17f1d3a73197d7942695638c4fa9ac0fc3688c4f9774b905a14e3a3f171bac586c55e83ff97a1aeffb3af00adb22c6bb08b3f481e3aaa0f1a09e30ed741d8ae4fcf5e095d5d00af600db18cb2c04b3edd03cc744a2888ae40caa232946c5e7e1").unwrap();
+    let id1 = from_hex(&format!("0xc0{:0>94}", "")).unwrap();
+    let id1_u = from_hex(&format!("0x40{:0>190}", "")).unwrap();
+
+    assert_eq!(Zcash::<G1>(G1::generator()).encode(), g1);
+    assert_eq!(Zcash::<G2>(G2::generator()).encode(), g2);
+    assert_eq!(Zcash::<G1, HOST_CALL>(G1::generator()).encode(), g1_u);
+    assert_eq!(Zcash::<G1>(G1::zero()).encode(), id1);
+    assert_eq!(Zcash::<G1, HOST_CALL>(G1::zero()).encode(), id1_u);
+    assert_eq!(<Zcash<G1>>::decode(&mut g1.as_slice()).unwrap().0, G1::generator());
+    assert_eq!(<Zcash<G2>>::decode(&mut g2.as_slice()).unwrap().0, G2::generator());
+    assert_eq!(<Zcash<G1, HOST_CALL>>::decode(&mut g1_u.as_slice()).unwrap().0, G1::generator());
+    assert!(<Zcash<G1>>::decode(&mut id1.as_slice()).unwrap().0.is_zero());
+
+    // Public keys and a signature from the Ethereum consensus BLS test
+    // vectors, which use this layout.
+    type Fr = ark_bls12_381::Fr;
+    for (sk, pk) in [
+        ("0x263dbd792f5b1be47ed85f8938c0f29586af0d3ac7b977f21c278fe1462040e3",
+         "0xa491d1b0ecd9bb917989f0e74f0dea0422eac4a873e5e2644f368dffb9a6e20fd6e10c1b77654d067c0618f6e5a7f79a"),
+        ("0x47b8192d77bf871b62e87859d653922725724a5c031afeabc60bcef5ff665138",
+         "0xb301803f8b5ac4a1133581fc676dfedc60d891dd5fa99028805e5ea5b08d3491af75d0707adab3b70c6a6a580217bf81"),
+        ("0x328388aff0d4a5b7dc9205abd374e7e98f3cd9f3418edb4eafda5fb16473d216",
+         "0xb53d21a4cfd562c469cc81514d4ce5a6b577d8403d32a394dc265dd190b47fa9f829fdd7963afdf972e5e77854051f6f"),
+    ] {
+        let p: G1 = (G1::generator() * Fr::from_be_bytes_mod_order(&from_hex(sk).unwrap())).into();
+        let pk = from_hex(pk).unwrap();
+        assert_eq!(Zcash::<G1>(p).encode(), pk);
+        assert_eq!(<Zcash<G1>>::decode(&mut pk.as_slice()).unwrap().0, p);
+        let u = Zcash::<G1, HOST_CALL>(p).encode();
+        assert_eq!(<Zcash<G1, HOST_CALL>>::decode(&mut u.as_slice()).unwrap().0, p);
+    }
+    // Signature by the first key upon the all zero message
+    let sig = from_hex("0xb6ed936746e01f8ecf281f020953fbf1f01debd5657c4a383940b020b26507f6076334f91e2366c96e9ab279fb5158090352ea1c5b0c9274504f4f0e7053af24802e51e4568d164fe986834f41e55c8e850ce1f98458c0cfc9ab380b55285a55").unwrap();
+    let s = <Zcash<G2>>::decode(&mut sig.as_slice()).unwrap().0;
+    assert_eq!(Zcash::<G2>(s).encode(), sig);
+    let u = Zcash::<G2, HOST_CALL>(s).encode();
+    // Uncompressed x is compressed x without flags.
+    let mut x = sig.clone();
+    x[0] &= 0x1f;
+    assert_eq!(u[..96], x[..]);
+    assert_eq!(<Zcash<G2, HOST_CALL>>::decode(&mut u.as_slice()).unwrap().0, s);
+
+    // Wrong compression flag, bad infinity, and x off the curve
+    assert!(<Zcash<G1, HOST_CALL>>::decode(&mut g1.as_slice()).is_err());
+    let mut bad = id1.clone();
+    bad[47] = 1;
+    assert!(<Zcash<G1>>::decode(&mut bad.as_slice()).is_err());
+    let mut bad = vec![0u8; 48];
+    bad[0] = 0x80;
+    bad[47] = 5;
+    assert!(<Zcash<G1>>::decode(&mut bad.as_slice()).is_err());
+
+    assert_eq!(<Zcash<G1> as MaxEncodedLen>::max_encoded_len(), 48);
+    assert_eq!(<Zcash<G2> as MaxEncodedLen>::max_encoded_len(), 96);
+    assert_eq!(<Zcash<G1, HOST_CALL> as MaxEncodedLen>::max_encoded_len(), 96);
+    assert_eq!(<Zcash<G2, HOST_CALL> as MaxEncodedLen>::max_encoded_len(), 192);
+
+    // ark-bls12-381 happens to serialize in this layout too.
+    for _ in 0..4 {
+        let p = <G2 as UniformRand>::rand(&mut rand_core::OsRng);
+        let z = Zcash::<G2>(p).encode();
+        assert_eq!(z, ArkScale::<G2>(p).encode());
+        assert_eq!(<Zcash<G2>>::decode(&mut z.as_slice()).unwrap().0, p);
+        let z = Zcash::<G2, HOST_CALL>(p).encode();
+        assert_eq!(z, ArkScale::<G2, HOST_CALL>(p).encode());
+        assert_eq!(<Zcash<G2, HOST_CALL>>::decode(&mut z.as_slice()).unwrap().0, p);
+    }
+}
+
+#[cfg(feature = "ec")]
+#[test]
+fn sec1() {
+    use ark_ec::AffineRepr;
+    use crate::ec::Sec1;
+    use crate::hex::from_hex;
+    type K1 = ark_secp256k1::Affine;
+    type R1 = ark_secp256r1::Affine;
+
+    let k1 = from_hex("0x0279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798").unwrap();
+    let k1_u = from_hex("0x0479be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798483ada7726a3c4655da4fbfc0e1108a8fd17b448a68554199c47d08ffb10d4b8").unwrap();
+    let r1 = from_hex("0x036b17d1f2e12c4247f8bce6e563a440f277037d812deb33a0f4a13945d898c296").unwrap();
+    let r1_u = from_hex("0x046b17d1f2e12c4247f8bce6e563a440f277037d812deb33a0f4a13945d898c2964fe342e2fe1a7f9b8ee7eb4a7c0f9e162bce33576b315ececbb6406837bf51f5").unwrap();
+
+    assert_eq!(Sec1::<K1>(K1::generator()).encode(), k1);
+    assert_eq!(Sec1::<K1, HOST_CALL>(K1::generator()).encode(), k1_u);
+    assert_eq!(Sec1::<R1>(R1::generator()).encode(), r1);
+    assert_eq!(Sec1::<R1, HOST_CALL>(R1::generator()).encode(), r1_u);
+    assert_eq!(<Sec1<K1>>::decode(&mut k1.as_slice()).unwrap().0, K1::generator());
+    assert_eq!(<Sec1<K1, HOST_CALL>>::decode(&mut k1_u.as_slice()).unwrap().0, K1::generator());
+    assert_eq!(<Sec1<R1>>::decode(&mut r1.as_slice()).unwrap().0, R1::generator());
+    assert_eq!(<Sec1<R1, HOST_CALL>>::decode(&mut r1_u.as_slice()).unwrap().0, R1::generator());
+    const UNCOMPRESSED_WIRE: Usage = make_usage(Compress::No, Validate::Yes);
+    assert_eq!(<Sec1<R1, UNCOMPRESSED_WIRE>>::decode(&mut r1_u.as_slice()).unwrap().0, R1::generator());
+
+    // Wrong form, flipped parity, and points off the curve
+    assert!(<Sec1<K1>>::decode(&mut k1_u.as_slice()).is_err());
+    let mut bad = k1.clone();
+    bad[0] = 0x03;
+    assert_eq!(<Sec1<K1>>::decode(&mut bad.as_slice()).unwrap().0, -K1::generator());
+    let mut bad = k1_u.clone();
+    bad[64] ^= 1;
+    assert!(<Sec1<K1, UNCOMPRESSED_WIRE>>::decode(&mut bad.as_slice()).is_err());
+    assert!(<Sec1<K1, HOST_CALL>>::decode(&mut bad.as_slice()).is_ok());
+
+    assert_eq!(Sec1::<K1>(K1::zero()).encode(), [0u8]);
+    assert!(<Sec1<K1>>::decode(&mut [0u8].as_slice()).unwrap().0.is_zero());
+    assert_eq!(<Sec1<K1> as MaxEncodedLen>::max_encoded_len(), 33);
+    assert_eq!(<Sec1<K1, HOST_CALL> as MaxEncodedLen>::max_encoded_len(), 65);
+    let ty = <Sec1<K1> as scale_info::TypeInfo>::type_info();
+    assert!(matches!(ty.type_def, scale_info::TypeDef::Variant(ref v) if v.variants.len() == 3));
+
+    for _ in 0..4 {
+        let p = <K1 as UniformRand>::rand(&mut rand_core::OsRng);
+        let e = Sec1::<K1>(p).encode();
+        assert_eq!(<Sec1<K1>>::decode(&mut e.as_slice()).unwrap().0, p);
+    }
+}
+
+#[cfg(feature = "ec")]
+#[test]
+fn rfc8032() {
+    use ark_ec::AffineRepr;
+    use crate::ec::{rfc8032::*, Rfc8032};
+    use crate::hex::from_hex;
+    type Ed = ark_ed25519::EdwardsAffine;
+
+    let base = from_hex("0x5866666666666666666666666666666666666666666666666666666666666666").unwrap();
+    assert_eq!(Rfc8032::<Ed>(Ed::generator()).encode(), base);
+    assert_eq!(<Rfc8032<Ed>>::decode(&mut base.as_slice()).unwrap().0, Ed::generator());
+    let mut neg = base.clone();
+    neg[31] |= 0x80;
+    assert_eq!(<Rfc8032<Ed>>::decode(&mut neg.as_slice()).unwrap().0, -Ed::generator());
+
+    // Public keys from RFC 8032 section 7.1, tests 1, 2, 3, and 1024
+    for pk in [
+        "0xd75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a",
+        "0x3d4017c3e843895a92b70aa74d1b7ebc9c982ccf2ec4968cc0cd55f12af4660c",
+        "0xfc51cd8e6218a1a38da47ed00230f0580816ed13ba3303ac5deb911548908025",
+        "0x278117fc144c72340f67d0f2316e8386ceffbf2b2428c9c51fef7c597f1d426e",
+    ] {
+        let pk = from_hex(pk).unwrap();
+        let p = <Rfc8032<Ed, STRICT>>::decode(&mut pk.as_slice()).unwrap().0;
+        assert!(p.is_in_correct_subgroup_assuming_on_curve());
+        assert_eq!(Rfc8032::<Ed>(p).encode(), pk);
+    }
+
+    let id = Rfc8032::<Ed>(Ed::zero()).encode();
+    assert_eq!(id[0], 1);
+    assert!(<Rfc8032<Ed>>::decode(&mut id.as_slice()).unwrap().0.is_zero());
+    assert!(<Rfc8032<Ed, STRICT>>::decode(&mut id.as_slice()).is_err());
+
+    // y = p is non-canonical, and reduces to y = 0 which has order 4.
+    let p = from_hex("0xedffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff7f").unwrap();
+    assert!(<Rfc8032<Ed>>::decode(&mut p.as_slice()).is_err());
+    assert!(<Rfc8032<Ed, 0>>::decode(&mut p.as_slice()).is_ok());
+    assert!(<Rfc8032<Ed, REJECT_SMALL_ORDER>>::decode(&mut p.as_slice()).is_err());
+
+    // x = 0 with the sign bit set
+    let mut neg_id = id.clone();
+    neg_id[31] |= 0x80;
+    assert!(<Rfc8032<Ed>>::decode(&mut neg_id.as_slice()).is_err());
+    assert!(<Rfc8032<Ed, 0>>::decode(&mut neg_id.as_slice()).unwrap().0.is_zero());
+
+    assert_eq!(<Rfc8032<Ed> as MaxEncodedLen>::max_encoded_len(), 32);
+    for _ in 0..4 {
+        let p = <Ed as UniformRand>::rand(&mut rand_core::OsRng);
+        let e = Rfc8032::<Ed>(p).encode();
+        assert_eq!(<Rfc8032<Ed>>::decode(&mut e.as_slice()).unwrap().0, p);
+    }
+}
+
+#[cfg(feature = "ristretto")]
+#[test]
+fn ristretto() {
+    use ark_ec::{AffineRepr, CurveGroup};
+    use ark_ff::Field;
+    use ark_std::{Zero, One};
+    use ark_ec::twisted_edwards::TECurveConfig;
+    use ark_ff::{BigInteger, PrimeField};
+    use crate::ec::ristretto::{RistrettoConfig, RistrettoScale};
+    use crate::hex::from_hex;
+    type Ed = ark_ed25519::EdwardsAffine;
+    type Fq = ark_ed25519::Fq;
+    type C = ark_ed25519::EdwardsConfig;
+
+    // Constants from RFC 9496 section 4.1, all non-negative
+    assert_eq!(Fq::MODULUS.as_ref()[0] % 8, 5);
+    assert_eq!(C::SQRT_M1.square(), -Fq::one());
+    assert_eq!(C::INVSQRT_A_MINUS_D.square() * (C::COEFF_A - C::COEFF_D), Fq::one());
+    for k in [C::SQRT_M1, C::INVSQRT_A_MINUS_D] {
+        assert!(k.into_bigint().is_even());
+    }
+
+    // Small multiples of the generator from RFC 9496 appendix A.1
+    let vectors = [
+        "0x0000000000000000000000000000000000000000000000000000000000000000",
+        "0xe2f2ae0a6abc4e71a884a961c500515f58e30b6aa582dd8db6a65945e08d2d76",
+        "0x6a493210f7499cd17fecb510ae0cea23a110e8d5b901f8acadd3095c73a3b919",
+        "0x94741f5d5d52755ece4f23f044ee27d5d1ea1e2bd196b462166b16152a9d0259",
+        "0xda80862773358b466ffadfe0b3293ab3d9fd53c5ea6c955358f568322daf6a57",
+        "0xe882b131016b52c1d3337080187cf768423efccbb517bb495ab812c4160ff44e",
+        "0xf64746d3c92b13050ed8d80236a7f0007c3b3f962f5ba793d19a601ebb1df403",
+        "0x44f53520926ec81fbd5a387845beb7df85a96a24ece18738bdcfa6a7822a176d",
+        "0x903293d8f2287ebe10e2374dc1a53e0bc887e592699f02d077d5263cdd55601c",
+        "0x02622ace8f7303a31cafc63f8fc48fdc16e1c8c8d234b2f0d6685282a9076031",
+        "0x20706fd788b2720a1ed2a5dad4952b01f413bcf0e7564de8cdc816689e2db95f",
+        "0xbce83f8ba5dd2fa572864c24ba1810f9522bc6004afe95877ac73241cafdab42",
+        "0xe4549ee16b9aa03099ca208c67adafcafa4c3f3e4e5303de6026e3ca8ff84460",
+        "0xaa52e000df2e16f55fb1032fc33bc42742dad6bd5a8fc0be0167436c5948501f",
+        "0x46376b80f409b29dc2b5f6f0c52591990896e5716f41477cd30085ab7f10301e",
+        "0xe0c418f7c8d9c4cdd7395b93ea124f3ad99021bb681dfc3302a9d99a2e53e64e",
+    ];
+    let mut p = Ed::zero().into_group();
+    for v in vectors {
+        let v = from_hex(v).unwrap();
+        assert_eq!(RistrettoScale(p.into_affine()).encode(), v);
+        let d = <RistrettoScale<Ed>>::decode(&mut v.as_slice()).unwrap();
+        assert_eq!(d, RistrettoScale(p.into_affine()));
+        assert_eq!(d.encode(), v);
+        p += Ed::generator();
+    }
+
+    // Non-canonical and negative encodings from RFC 9496 appendix A.2
+    for bad in [
+        "0x00ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff",
+        "0xffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff7f",
+        "0xedffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff7f",
+        "0x0100000000000000000000000000000000000000000000000000000000000000",
+        "0x01ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff7f",
+    ] {
+        assert!(<RistrettoScale<Ed>>::decode(&mut from_hex(bad).unwrap().as_slice()).is_err());
+    }
+
+    // Equality and encodings ignore 4-torsion.
+    let sqrt_m1 = (-Fq::one()).sqrt().unwrap();
+    let t4 = Ed::new_unchecked(sqrt_m1, Fq::zero());
+    let t2 = Ed::new_unchecked(Fq::zero(), -Fq::one());
+    let q = <Ed as UniformRand>::rand(&mut rand_core::OsRng);
+    let e = RistrettoScale(q).encode();
+    for t in [t2, t4] {
+        let qt = (q + t).into_affine();
+        assert_ne!(q, qt);
+        assert_eq!(RistrettoScale(q), RistrettoScale(qt));
+        assert_eq!(RistrettoScale(qt).encode(), e);
+    }
+    assert_ne!(RistrettoScale(q), RistrettoScale(Ed::generator()));
+    assert_eq!(<RistrettoScale<Ed> as MaxEncodedLen>::max_encoded_len(), 32);
+}
+
+#[cfg(feature = "ec")]
+#[test]
+fn bip340() {
+    use ark_ec::AffineRepr;
+    use ark_ff::{BigInteger, PrimeField};
+    use crate::ec::XOnly;
+    use crate::hex::from_hex;
+    type K1 = ark_secp256k1::Affine;
+    type Fr = ark_secp256k1::Fr;
+
+    // Secret and public keys from the BIP340 test vectors
+    for (sk, pk) in [
+        ("0x0000000000000000000000000000000000000000000000000000000000000003",
+         "0xf9308a019258c31049344f85f89d5229b531c845836f99b08601f113bce036f9"),
+        ("0xb7e151628aed2a6abf7158809cf4f3c762e7160f38b4da56a784d9045190cfef",
+         "0xdff1d77f2a671c5f36183726db2341be58feae1da2deced843240f7b502ba659"),
+        ("0xc90fdaa22168c234c4c6628b80dc1cd129024e088a67cc74020bbea63b14e5c9",
+         "0xdd308afec5777e13121fa72b9cc1b7cc0139715309b086c960e18fd969774eb8"),
+        ("0x0b432b2677937381aef05bb02a66ecd012773062cf3fa2549e44f58ed2401710",
+         "0x25d1dff95105f5253c4022f628a996ad3a0d95fbf21d468a1b33f8c160d8f517"),
+    ] {
+        let p: K1 = (K1::generator() * Fr::from_be_bytes_mod_order(&from_hex(sk).unwrap())).into();
+        let pk = from_hex(pk).unwrap();
+        let xp = XOnly::new(p).unwrap();
+        assert_eq!(xp.encode(), pk);
+        assert_eq!(XOnly::new(-p).unwrap().encode(), pk);
+        let xq = <XOnly<K1>>::decode(&mut pk.as_slice()).unwrap();
+        assert_eq!(xq, xp);
+        let q = xq.into_inner();
+        assert!(q == p || q == -p);
+        assert!(q.y.into_bigint().is_even());
+    }
+
+    // Public key not on the curve, and exceeding the field size
+    for bad in [
+        "0xeefdea4cdb677750a420fee807eacf21eb9898ae79b9768766e4faa04a2d4a34",
+        "0xfffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc30",
+    ] {
+        assert!(<XOnly<K1>>::decode(&mut from_hex(bad).unwrap().as_slice()).is_err());
+    }
+    assert!(XOnly::new(K1::zero()).is_none());
+    assert_eq!(<XOnly<K1> as MaxEncodedLen>::max_encoded_len(), 32);
+}
+
+#[cfg(feature = "ec")]
+#[test]
+fn torus() {
+    use ark_ec::pairing::{Pairing, PairingOutput};
+    use ark_std::{One, Zero};
+    use crate::ec::{Torus, Torus6};
+    type E = ark_bls12_381::Bls12_381;
+    type Gt = PairingOutput<E>;
+    type Fq6 = ark_bls12_381::Fq6;
+    type Fq12 = ark_bls12_381::Fq12;
+
+    for _ in 0..2 {
+        let a = <ark_bls12_381::G1Affine as UniformRand>::rand(&mut rand_core::OsRng);
+        let b = <ark_bls12_381::G2Affine as UniformRand>::rand(&mut rand_core::OsRng);
+        let g = E::pairing(a, b);
+        let e = Torus::<Gt>(g).encode();
+        assert_eq!(e.len(), 288);
+        assert_eq!(2 * e.len(), ArkScale::<Gt>(g).encode().len());
+        assert_eq!(<Torus<Gt>>::decode(&mut e.as_slice()).unwrap().0, g);
+        let e = Torus::<Gt, HOST_CALL>(-g).encode();
+        assert_eq!(<Torus<Gt, HOST_CALL>>::decode(&mut e.as_slice()).unwrap().0, -g);
+
+        let e = Torus6::<Gt>(g).encode();
+        assert_eq!(e.len(), 192);
+        assert_eq!(3 * e.len(), ArkScale::<Gt>(g).encode().len());
+        assert_eq!(<Torus6<Gt>>::decode(&mut e.as_slice()).unwrap().0, g);
+        let e = Torus6::<Gt, HOST_CALL>(-g).encode();
+        assert_eq!(<Torus6<Gt, HOST_CALL>>::decode(&mut e.as_slice()).unwrap().0, -g);
+    }
+
+    let id = Torus::<Gt>(Gt::zero()).encode();
+    assert_ne!(ArkScale::<Fq6>(Fq6::zero()).encode(), id);
+    assert!(<Torus<Gt>>::decode(&mut id.as_slice()).unwrap().0.is_zero());
+    let id = Torus6::<Gt>(Gt::zero()).encode();
+    assert!(<Torus6<Gt>>::decode(&mut id.as_slice()).unwrap().0.is_zero());
+
+    // -1 has norm one over Fp6, so Torus encodes it distinctly from the
+    // identity, but lies outside the target group.
+    let m1 = Torus::<Gt, HOST_CALL>(PairingOutput(-Fq12::one())).encode();
+    assert_eq!(ArkScale::<Fq6>(Fq6::zero()).encode(), m1);
+    assert_eq!(<Torus<Gt, HOST_CALL>>::decode(&mut m1.as_slice()).unwrap().0 .0, -Fq12::one());
+    assert!(<Torus<Gt>>::decode(&mut m1.as_slice()).is_err());
+
+    // Arbitrary torus elements lie outside the target group.
+    let junk = ArkScale::<Fq6>(Fq6::one()).encode();
+    assert!(<Torus<Gt>>::decode(&mut junk.as_slice()).is_err());
+    assert!(<Torus<Gt, HOST_CALL>>::decode(&mut junk.as_slice()).is_ok());
+    assert!(<Torus6<Gt, HOST_CALL>>::decode(&mut &junk[..192]).is_err());
+    assert_eq!(<Torus<Gt> as MaxEncodedLen>::max_encoded_len(), 288);
+    assert_eq!(<Torus6<Gt> as MaxEncodedLen>::max_encoded_len(), 192);
+    assert_eq!(<Torus6<PairingOutput<ark_bn254::Bn254>> as MaxEncodedLen>::max_encoded_len(), 128);
+}
+
+#[cfg(feature = "ec")]
+fn check_pairing_lens<P: ark_ec::pairing::Pairing>()
+where
+    P::G1Prepared: ArkScaleMaxEncodedLen,
+    P::G2Prepared: ArkScaleMaxEncodedLen,
+{
+    use ark_ec::{pairing::PairingOutput, AffineRepr};
+    for c in [Compress::Yes, Compress::No] {
+        let g1 = P::G1Prepared::from(P::G1Affine::generator());
+        let g2 = P::G2Prepared::from(P::G2Affine::generator());
+        let gt = P::pairing(P::G1Affine::generator(), P::G2Affine::generator());
+        assert_eq!(g1.serialized_size(c), <P::G1Prepared as ArkScaleMaxEncodedLen>::max_encoded_len(c));
+        assert_eq!(g2.serialized_size(c), <P::G2Prepared as ArkScaleMaxEncodedLen>::max_encoded_len(c));
+        assert_eq!(gt.serialized_size(c), <PairingOutput<P> as ArkScaleMaxEncodedLen>::max_encoded_len(c));
+    }
+}
+
+#[cfg(feature = "ec")]
+#[test]
+fn pairing_lens() {
+    check_pairing_lens::<ark_bls12_381::Bls12_381>();
+    check_pairing_lens::<ark_bn254::Bn254>();
+    check_pairing_lens::<ark_bw6_761::BW6_761>();
+    check_pairing_lens::<ark_mnt4_298::MNT4_298>();
+    check_pairing_lens::<ark_mnt6_298::MNT6_298>();
+    type G2Prepared = ark_ec::bls12::G2Prepared<ark_bls12_381::Config>;
+    assert_eq!(
+        <ArkScale<G2Prepared, HOST_CALL> as MaxEncodedLen>::max_encoded_len(),
+        ArkScale::<_, HOST_CALL>(G2Prepared::default()).encode().len()
+    );
+}
+
+#[cfg(feature = "hazmat")]
+#[test]
+fn host_calls() {
+    use ark_ec::{pairing::Pairing, CurveGroup, PrimeGroup, VariableBaseMSM};
+    use ark_ff::PrimeField;
+    use ark_std::{vec, Zero};
+    use crate::host::*;
+    type E = ark_bls12_381::Bls12_381;
+    type G1 = ark_bls12_381::G1Projective;
+    type Ed = ark_ed25519::EdwardsProjective;
+    let rng = &mut rand_core::OsRng;
+
+    let bases: Vec<_> = (0..5).map(|_| G1::rand(rng).into_affine()).collect();
+    let scalars: Vec<_> = (0..5).map(|_| ark_bls12_381::Fr::rand(rng)).collect();
+    let expected = G1::msm(&bases, &scalars).unwrap().into_affine();
+    let call = Msm::<G1> { bases: bases.clone(), scalars };
+    assert_eq!(call_software_host(&call).unwrap().0, expected);
+    let mut bad = call.encode();
+    bad.push(0);
+    assert!(software_host::<Msm<G1>>(&bad).is_err());
+    let short = Msm::<G1> { bases, scalars: vec![] };
+    assert!(software_host::<Msm<G1>>(&short.encode()).is_err());
+
+    let bases: Vec<_> = (0..3).map(|_| Ed::rand(rng).into_affine()).collect();
+    let scalars: Vec<_> = (0..3).map(|_| ark_ed25519::Fr::rand(rng)).collect();
+    let expected = Ed::msm(&bases, &scalars).unwrap().into_affine();
+    assert_eq!(call_software_host(&Msm::<Ed> { bases, scalars }).unwrap().0, expected);
+
+    let s = ark_bls12_381::Fr::rand(rng);
+    let base = G1::rand(rng);
+    let scalar = s.into_bigint().0.to_vec();
+    let out = call_software_host(&MulProjective { base, scalar }).unwrap().0;
+    assert_eq!(out, base * s);
+    let s = ark_ed25519::Fr::rand(rng);
+    let base = Ed::rand(rng);
+    let scalar = s.into_bigint().0.to_vec();
+    let out = call_software_host(&MulProjective { base, scalar }).unwrap().0;
+    assert_eq!(out, base.mul_bigint(s.into_bigint()));
+
+    let a = vec![<E as Pairing>::G1Affine::rand(rng), <E as Pairing>::G1Affine::rand(rng)];
+    let b = vec![<E as Pairing>::G2Affine::rand(rng), <E as Pairing>::G2Affine::rand(rng)];
+    let f = call_software_host(&MultiMillerLoop::<E> { a: a.clone(), b: b.clone() }).unwrap().0;
+    let gt = call_software_host(&FinalExponentiation::<E> { f }).unwrap().unwrap().0;
+    assert_eq!(gt, E::multi_pairing(a, b));
+    let none = FinalExponentiation::<E> { f: Zero::zero() };
+    assert!(call_software_host(&none).unwrap().is_none());
+}
+
+#[cfg(feature = "hazmat")]
+fn check_montgomery<T>(t: T)
+where
+    T: crate::hazmat::MontgomeryLimbs + Clone + PartialEq + Debug,
+{
+    use crate::hazmat::ArkScaleMontgomery;
+    let e = ArkScaleMontgomery(t.clone()).encode();
+    assert_eq!(e.len(), ArkScaleMontgomery(t.clone()).encoded_size());
+    let d = <ArkScaleMontgomery<T>>::decode(&mut e.as_slice()).unwrap().0;
+    assert_eq!(d, t);
+}
+
+#[cfg(feature = "hazmat")]
+#[test]
+fn montgomery() {
+    use ark_ec::CurveGroup;
+    use crate::hazmat::ArkScaleMontgomery;
+    let rng = &mut rand_core::OsRng;
+
+    let f = ark_bls12_381::Fq::rand(rng);
+    check_montgomery(f);
+    // Raw limbs are the Montgomery form, not the canonical integer.
+    assert_eq!(ArkScaleMontgomery(f).encode(), f.0 .0.encode());
+    assert_ne!(ArkScaleMontgomery(f).encode(), ArkScale::<_, HOST_CALL>(f).encode());
+    check_montgomery(ark_bls12_381::Fq2::rand(rng));
+    check_montgomery(ark_bls12_381::Fq12::rand(rng));
+    check_montgomery(ark_bls12_381::G1Projective::rand(rng));
+    check_montgomery(ark_bls12_381::G2Projective::rand(rng).into_affine());
+    check_montgomery(ark_bls12_381::G1Affine::identity());
+    let v: Vec<_> = (0..4).map(|_| ark_bls12_381::G1Affine::rand(rng)).collect();
+    check_montgomery(v);
+
+    check_montgomery(ark_ed25519::Fq::rand(rng));
+    check_montgomery(ark_ed25519::Fr::rand(rng));
+    check_montgomery(ark_ed25519::EdwardsProjective::rand(rng));
+    check_montgomery(ark_ed25519::EdwardsAffine::rand(rng));
+
+    let mut junk = ArkScaleMontgomery(ark_bls12_381::G1Affine::identity()).encode();
+    *junk.last_mut().unwrap() = 2;
+    assert!(<ArkScaleMontgomery<ark_bls12_381::G1Affine>>::decode(&mut junk.as_slice()).is_err());
+}
+
+#[cfg(feature = "hazmat")]
+#[test]
+fn projective_checks() {
+    use ark_ec::{models::{short_weierstrass as sw, twisted_edwards as te}, AdditiveGroup, AffineRepr};
+    use ark_ff::{One, Zero};
+    use crate::hazmat::*;
+    type G1 = ark_bls12_381::G1Projective;
+    type Ed = ark_ed25519::EdwardsProjective;
+    let rng = &mut rand_core::OsRng;
+
+    // Short Weierstrass in Jacobian coordinates, never normalized.
+    let p = G1::rand(rng).double();
+    assert!(!p.z.is_one());
+    let e = ArkScaleProjective(&p).encode();
+    assert_eq!(<ArkScaleProjectiveChecked<G1, CHECKED>>::decode(&mut e.as_slice()).unwrap().0, p);
+    let e = ArkScaleProjective(&G1::zero()).encode();
+    assert!(<ArkScaleProjectiveChecked<G1, CHECKED>>::decode(&mut e.as_slice()).unwrap().0.is_zero());
+
+    let mut q = p;
+    q.y += ark_bls12_381::Fq::one();
+    let e = ArkScaleProjective(&q).encode();
+    assert!(<ArkScaleProjectiveChecked<G1, ON_CURVE>>::decode(&mut e.as_slice()).is_err());
+    assert!(<ArkScaleProjective<G1>>::decode(&mut e.as_slice()).is_ok());
+    let e = ArkScaleProjective(&G1::new_unchecked(Zero::zero(), Zero::zero(), Zero::zero())).encode();
+    assert!(<ArkScaleProjectiveChecked<G1, ON_CURVE>>::decode(&mut e.as_slice()).is_err());
+
+    let torsion = (1u64..)
+        .filter_map(|x| sw::Affine::<ark_bls12_381::g1::Config>::get_point_from_x_unchecked(x.into(), true))
+        .find(|p| !p.is_in_correct_subgroup_assuming_on_curve())
+        .unwrap();
+    let e = ArkScaleProjective(&torsion.into_group().double()).encode();
+    assert!(<ArkScaleProjectiveChecked<G1, ON_CURVE>>::decode(&mut e.as_slice()).is_ok());
+    assert!(<ArkScaleProjectiveChecked<G1, IN_SUBGROUP>>::decode(&mut e.as_slice()).is_err());
+
+    // Twisted Edwards in extended coordinates
+    let p = Ed::rand(rng).double();
+    assert!(!p.z.is_one());
+    let e = ArkScaleProjective(&p).encode();
+    assert_eq!(<ArkScaleProjectiveChecked<Ed, CHECKED>>::decode(&mut e.as_slice()).unwrap().0, p);
+
+    let mut q = p;
+    q.t += ark_ed25519::Fq::one();
+    let e = ArkScaleProjective(&q).encode();
+    assert!(<ArkScaleProjectiveChecked<Ed, ON_CURVE>>::decode(&mut e.as_slice()).is_err());
+    let e = ArkScaleProjective(&Ed::new_unchecked(p.x, p.y, p.t, Zero::zero())).encode();
+    assert!(<ArkScaleProjectiveChecked<Ed, ON_CURVE>>::decode(&mut e.as_slice()).is_err());
+
+    let torsion = (2u64..)
+        .filter_map(|y| te::Affine::<ark_ed25519::EdwardsConfig>::get_point_from_y_unchecked(y.into(), true))
+        .find(|p| !p.is_in_correct_subgroup_assuming_on_curve())
+        .unwrap();
+    let e = ArkScaleProjective(&torsion.into_group().double()).encode();
+    assert!(<ArkScaleProjectiveChecked<Ed, ON_CURVE>>::decode(&mut e.as_slice()).is_ok());
+    assert!(<ArkScaleProjectiveChecked<Ed, CHECKED>>::decode(&mut e.as_slice()).is_err());
+}
+
+#[cfg(feature = "hazmat")]
+#[test]
+fn projective_vectors() {
+    use ark_ec::AdditiveGroup;
+    use ark_ff::{Field, One, Zero};
+    use ark_std::vec;
+    use crate::hazmat::*;
+    type G1 = ark_bls12_381::G1Projective;
+    type Ed = ark_ed25519::EdwardsProjective;
+    let rng = &mut rand_core::OsRng;
+
+    let v = vec![G1::rand(rng).double(), G1::zero(), G1::rand(rng)];
+    let e = ArkScaleProjective(v.clone()).encode();
+    assert_eq!(e, ArkScaleProjective(v.as_slice()).encode());
+    assert_eq!(e.len(), ArkScaleProjective(v.clone()).size_hint());
+    assert_eq!(e.len(), 8 + 3 * 3 * 48);
+    let d = <ArkScaleProjectiveChecked<Vec<G1>, CHECKED>>::decode(&mut e.as_slice()).unwrap().0;
+    assert!(d.iter().zip(&v).all(|(a, b)| a.x == b.x && a.y == b.y && a.z == b.z));
+
+    // Normalized encodings do not depend upon the representative.
+    let mut w = v.clone();
+    let s = ark_bls12_381::Fq::rand(rng);
+    w[0] = G1::new_unchecked(w[0].x * s.square(), w[0].y * s.square() * s, w[0].z * s);
+    assert_eq!(w, v);
+    assert_ne!(ArkScaleProjective(w.clone()).encode(), e);
+    let n = ArkScaleProjective(Normalized(v.clone())).encode();
+    assert_eq!(n, ArkScaleProjective(Normalized(w.as_slice())).encode());
+    assert_eq!(n[8..8 + 144], ArkScaleProjective(Normalized(&w[0])).encode());
+    assert_eq!(n[8..8 + 144], ArkScaleProjective(Normalized(w[0])).encode());
+    assert_eq!(<ArkScaleProjective<Normalized<G1>> as MaxEncodedLen>::max_encoded_len(), 144);
+    let d = <ArkScaleProjectiveChecked<Vec<G1>, CHECKED>>::decode(&mut n.as_slice()).unwrap().0;
+    assert_eq!(d, v);
+    assert!(d[0].z.is_one() && d[1].z.is_zero() && d[2].z.is_one());
+
+    let v = vec![Ed::rand(rng).double(), Ed::zero()];
+    let n = ArkScaleProjective(Normalized(v.clone())).encode();
+    assert_eq!(n.len(), 8 + 2 * 4 * 32);
+    let d = <ArkScaleProjectiveChecked<Vec<Ed>, CHECKED>>::decode(&mut n.as_slice()).unwrap().0;
+    assert_eq!(d, v);
+    assert!(d.iter().all(|p| p.z.is_one()));
+
+    let mut junk = n.clone();
+    junk[0] = 3;
+    assert!(<ArkScaleProjective<Vec<Ed>>>::decode(&mut junk.as_slice()).is_err());
+}
+
+#[cfg(feature = "hazmat")]
+#[test]
+fn projective_traits() {
+    use ark_ec::AdditiveGroup;
+    use crate::hazmat::*;
+    type G2 = ark_bls12_381::G2Projective;
+    type Ed = ark_ed25519::EdwardsProjective;
+    let rng = &mut rand_core::OsRng;
+
+    let p = G2::rand(rng).double();
+    let q = G2::new_unchecked(p.x * ark_bls12_381::Fq2::from(4u64), p.y * ark_bls12_381::Fq2::from(8u64), p.z + p.z);
+    assert_eq!(ArkScaleProjective(p), ArkScaleProjective(q));
+    assert_ne!(ArkScaleProjective(p), ArkScaleProjective(p.double()));
+
+    let e = ArkScaleProjective(&p).encode();
+    assert_eq!(e.len(), 3 * 96);
+    assert_eq!(e.len(), <ArkScaleProjective<G2> as MaxEncodedLen>::max_encoded_len());
+    assert_eq!(e.len(), ArkScaleProjective(&p).encoded_size());
+    assert_eq!(<ArkScaleProjective<G2> as Decode>::encoded_fixed_size(), Some(e.len()));
+    let mut e2 = [e.as_slice(), &[7u8]].concat();
+    let mut input = e2.as_slice();
+    <ArkScaleProjectiveChecked<G2, CHECKED> as Decode>::skip(&mut input).unwrap();
+    assert_eq!(input, &[7u8]);
+    e2.truncate(100);
+    assert!(<ArkScaleProjective<G2> as Decode>::skip(&mut e2.as_slice()).is_err());
+
+    let p = Ed::rand(rng);
+    assert_eq!(ArkScaleProjective(p).encoded_size(), 4 * 32);
+    assert_eq!(<ArkScaleProjective<Ed> as MaxEncodedLen>::max_encoded_len(), 4 * 32);
+    let ty = <ArkScaleProjective<&'static Ed> as scale_info::TypeInfo>::type_info();
+    assert!(matches!(ty.type_def, scale_info::TypeDef::Array(ref a) if a.len == 128));
+
+    fn encode_like<T: Encode, R: scale_codec::EncodeLike<T>>(r: R) -> Vec<u8> {
+        r.encode()
+    }
+    assert_eq!(encode_like::<ArkScaleProjective<Ed>, _>(ArkScaleProjective(&p)), ArkScaleProjective(p).encode());
+
+    let e = ArkScaleProjective(&p).encode();
+    assert_eq!(ArkScaleProjective::<&Ed>::decode_owned(&mut e.as_slice()).unwrap().0, p);
+    let v = [p, p.double()];
+    let e = ArkScaleProjective(&v[..]).encode();
+    assert_eq!(ArkScaleProjective::<&[Ed]>::decode_owned(&mut e.as_slice()).unwrap().0, v);
+    let d = ArkScaleProjectiveChecked::<&[Ed], CHECKED>::decode_owned(&mut e.as_slice()).unwrap().0;
+    assert_eq!(d, v);
+}
+
+#[cfg(all(feature = "serde", feature = "ec"))]
+#[test]
+fn serde() {
+    use ark_std::{format, string::String};
+    type G1 = ark_bls12_381::G1Affine;
+    type Fr = ark_bls12_381::Fr;
+
+    #[derive(::serde::Serialize, ::serde::Deserialize, PartialEq, Debug)]
+    struct Bare {
+        #[serde(with = "crate::serde::wire")]
+        point: G1,
+        #[serde(with = "crate::serde::host_call")]
+        scalar: Fr,
+    }
+
+    let p = <G1 as UniformRand>::rand(&mut rand_core::OsRng);
+    let wire = ArkScale::<G1>(p).encode();
+    let json = serde_json::to_string(&ArkScale::<G1>(p)).unwrap();
+    assert_eq!(json, format!("\"{}\"", crate::hex::to_hex(&wire)));
+    assert_eq!(json, serde_json::to_string(&ArkScaleRef::<G1>(&p)).unwrap());
+    assert_eq!(serde_json::from_str::<ArkScale<G1>>(&json).unwrap().0, p);
+    assert_eq!(serde_json::from_str::<ArkScale<G1>>(&json.to_uppercase().replace("0X", "0x")).unwrap().0, p);
+
+    let bin = bincode::serialize(&ArkScale::<G1, HOST_CALL>(p)).unwrap();
+    assert_eq!(bin[8..], ArkScale::<G1, HOST_CALL>(p).encode());
+    assert_eq!(bincode::deserialize::<ArkScale<G1, HOST_CALL>>(&bin).unwrap().0, p);
+
+    let bare = Bare { point: p, scalar: Fr::rand(&mut rand_core::OsRng) };
+    let json = serde_json::to_string(&bare).unwrap();
+    assert_eq!(serde_json::from_str::<Bare>(&json).unwrap(), bare);
+    assert_eq!(bincode::deserialize::<Bare>(&bincode::serialize(&bare).unwrap()).unwrap(), bare);
+
+    // Validation, prefix, and trailing byte errors all surface through serde.
+    let junk: String = format!("\"0x{}\"", "ff".repeat(48));
+    assert!(serde_json::from_str::<ArkScale<G1>>(&junk).is_err());
+    assert!(serde_json::from_str::<ArkScale<G1, HOST_CALL>>(&junk).is_err());
+    let unprefixed = format!("\"{}\"", &crate::hex::to_hex(&wire)[2..]);
+    assert!(serde_json::from_str::<ArkScale<G1>>(&unprefixed).is_err());
+    let long = format!("\"{}00\"", crate::hex::to_hex(&wire));
+    assert!(serde_json::from_str::<ArkScale<G1>>(&long).is_err());
+}
+
+#[cfg(all(feature = "borsh", feature = "ec"))]
+#[test]
+fn borsh() {
+    use ::borsh::{BorshDeserialize, BorshSerialize};
+    use ark_std::vec;
+    type G1 = ark_bls12_381::G1Affine;
+    type Ed = ark_ed25519::EdwardsAffine;
+
+    #[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug)]
+    #[borsh(crate = "::borsh")]
+    struct Msg {
+        keys: Vec<ArkScale<G1>>,
+        point: ArkScale<Ed, HOST_CALL>,
+    }
+
+    let p = <G1 as UniformRand>::rand(&mut rand_core::OsRng);
+    let q = <Ed as UniformRand>::rand(&mut rand_core::OsRng);
+    assert_eq!(::borsh::to_vec(&ArkScale::<G1>(p)).unwrap(), ArkScale::<G1>(p).encode());
+    assert_eq!(::borsh::to_vec(&ArkScaleRef::<Ed, HOST_CALL>(&q)).unwrap(), ArkScale::<Ed, HOST_CALL>(q).encode());
+    let len = ::borsh::to_vec(&ArkScaleLen::<G1, 48>(p)).unwrap();
+    assert_eq!(len, ArkScaleLen::<G1, 48>(p).encode());
+    assert_eq!(<ArkScaleLen<G1, 48>>::try_from_slice(&len).unwrap().0, p);
+
+    let msg = Msg { keys: vec![ArkScale(p), ArkScale(-p)], point: ArkScale(q) };
+    let bytes = ::borsh::to_vec(&msg).unwrap();
+    assert_eq!(bytes[..4], 2u32.to_le_bytes());
+    assert_eq!(bytes[4..52], ArkScale::<G1>(p).encode());
+    assert_eq!(Msg::try_from_slice(&bytes).unwrap(), msg);
+
+    // Validation and truncation errors surface as Borsh errors.
+    assert!(<ArkScale<G1>>::try_from_slice(&[0xffu8; 48]).is_err());
+    assert!(<ArkScale<G1>>::try_from_slice(&bytes[4..40]).is_err());
+    assert!(Msg::try_from_slice(&bytes[..bytes.len() - 1]).is_err());
+}
+
+#[cfg(all(feature = "dynamic", feature = "ec"))]
+#[test]
+fn dynamic() {
+    use scale_decode::DecodeAsType;
+    use scale_encode::EncodeAsType;
+    use scale_info::{MetaType, PortableRegistry, Registry};
+    type G1 = ark_bls12_381::G1Affine;
+
+    let mut registry = Registry::new();
+    let wire = registry.register_type(&MetaType::new::<ArkScale<G1>>()).id;
+    let host = registry.register_type(&MetaType::new::<ArkScale<G1, HOST_CALL>>()).id;
+    let bytes = registry.register_type(&MetaType::new::<[u8; 48]>()).id;
+    let words = registry.register_type(&MetaType::new::<[u32; 48]>()).id;
+    let types: PortableRegistry = registry.into();
+
+    let p = <G1 as UniformRand>::rand(&mut rand_core::OsRng);
+    let e = ArkScale::<G1>(p).encode_as_type(wire, &types).unwrap();
+    assert_eq!(e, ArkScale::<G1>(p).encode());
+    // Type resolvers expose no array paths, so plain byte arrays of the
+    // same length pass too.
+    assert!(ArkScale::<G1>(p).encode_as_type(bytes, &types).is_ok());
+    assert!(ArkScale::<G1>(p).encode_as_type(host, &types).is_err());
+    assert!(ArkScale::<G1>(p).encode_as_type(words, &types).is_err());
+
+    let mut input = e.as_slice();
+    assert_eq!(<ArkScale<G1>>::decode_as_type(&mut input, wire, &types).unwrap().0, p);
+    assert!(input.is_empty());
+    assert!(<ArkScale<G1>>::decode_as_type(&mut e.as_slice(), words, &types).is_err());
+    assert_eq!(<ArkScale<G1>>::decode_as_type(&mut e.as_slice(), bytes, &types).unwrap().0, p);
+    let e = ArkScale::<G1, HOST_CALL>(p).encode_as_type(host, &types).unwrap();
+    assert_eq!(<ArkScale<G1, HOST_CALL>>::decode_as_type(&mut e.as_slice(), host, &types).unwrap().0, p);
+    assert!(<ArkScale<G1>>::decode_as_type(&mut [0xffu8; 48].as_slice(), wire, &types).is_err());
+}
+
+#[cfg(feature = "scale-value")]
+#[test]
+fn scale_value() {
+    use ark_ff::{BigInteger, PrimeField};
+    use scale_value::{Composite, Primitive, Value, ValueDef};
+    type G1 = ark_bls12_381::G1Affine;
+    type G2 = ark_bls12_381::G2Affine;
+
+    let p = <G1 as UniformRand>::rand(&mut rand_core::OsRng);
+    let v = Value::from(ArkScale::<G1>(p));
+    let ValueDef::Variant(var) = v.value else { panic!() };
+    assert_eq!(var.name, "Point");
+    let Composite::Named(fields) = var.values else { panic!() };
+    assert_eq!(fields[0].0, "x");
+    let x = crate::hex::to_hex(&p.x.into_bigint().to_bytes_be());
+    assert!(matches!(&fields[0].1.value, ValueDef::Primitive(Primitive::String(s)) if *s == x));
+
+    let v = Value::from(ArkScale::<G1>(G1::identity()));
+    assert!(matches!(v.value, ValueDef::Variant(ref var) if var.name == "Identity"));
+    let q = <G2 as UniformRand>::rand(&mut rand_core::OsRng);
+    let v = Value::from(ArkScale::<G2>(q));
+    let ValueDef::Variant(var) = v.value else { panic!() };
+    let Composite::Named(fields) = var.values else { panic!() };
+    assert!(matches!(&fields[1].1.value, ValueDef::Composite(Composite::Unnamed(c)) if c.len() == 2));
+
+    let e = <ark_ed25519::EdwardsAffine as UniformRand>::rand(&mut rand_core::OsRng);
+    let v = Value::from(ArkScale::<_, HOST_CALL>(e));
+    assert!(matches!(v.value, ValueDef::Composite(Composite::Named(ref f)) if f.len() == 2));
+}
+
+#[cfg(feature = "ec")]
+#[test]
+fn encoded_as() {
+    use ark_std::vec;
+    type G1 = ark_bls12_381::G1Affine;
+    type G2 = ark_bls12_381::G2Projective;
+    type Fr = ark_bls12_381::Fr;
+    const UNCOMPRESSED: Usage = make_usage(Compress::No, Validate::Yes);
+    const UNVALIDATED: Usage = make_usage(Compress::Yes, Validate::No);
+
+    #[derive(Encode, Decode, PartialEq, Debug)]
+    #[codec(crate = scale_codec)]
+    struct S {
+        #[codec(encoded_as = "ArkScale<G1>")]
+        wire: G1,
+        #[codec(encoded_as = "ArkScale<G1, HOST_CALL>")]
+        host_call: G1,
+        #[codec(encoded_as = "ArkScale<Fr, UNCOMPRESSED>")]
+        uncompressed: Fr,
+        #[codec(encoded_as = "ArkScale<Vec<G2>, UNVALIDATED>")]
+        unvalidated: Vec<G2>,
+        n: u32,
+    }
+
+    let rng = &mut rand_core::OsRng;
+    let s = S {
+        wire: G1::rand(rng),
+        host_call: G1::rand(rng),
+        uncompressed: Fr::rand(rng),
+        unvalidated: vec![G2::rand(rng), G2::rand(rng)],
+        n: 7,
+    };
+    let e = s.encode();
+    let expected = [
+        ArkScale::<G1>(s.wire).encode(),
+        ArkScale::<G1, HOST_CALL>(s.host_call).encode(),
+        ArkScale::<Fr, UNCOMPRESSED>(s.uncompressed).encode(),
+        ArkScale::<_, UNVALIDATED>(s.unvalidated.clone()).encode(),
+        7u32.encode(),
+    ]
+    .concat();
+    assert_eq!(e, expected);
+    assert_eq!(e.len(), s.size_hint());
+    assert_eq!(S::decode(&mut e.as_slice()).unwrap(), s);
+    assert!(S::decode(&mut &e[..e.len() - 5]).is_err());
+}
+
+#[cfg(feature = "ec")]
+#[test]
+fn std_traits() {
+    use ark_std::{collections::BTreeSet, format, string::ToString, vec::Vec, Zero};
+    type G1 = ark_bls12_381::G1Affine;
+    type Fr = ark_bls12_381::Fr;
+
+    let rng = &mut rand_core::OsRng;
+    let points: Vec<ArkScale<G1>> = (0..8).map(|_| ArkScale(G1::rand(rng))).collect();
+
+    let set: BTreeSet<_> = points.iter().cloned().chain(points.iter().cloned()).collect();
+    assert_eq!(set.len(), points.len());
+    let sorted: Vec<Vec<u8>> = set.iter().map(|p| p.encode()).collect();
+    assert!(sorted.windows(2).all(|w| w[0] < w[1]));
+    let refs: BTreeSet<ArkScaleRef<G1>> = points.iter().map(|p| ArkScaleRef(&p.0)).collect();
+    assert!(refs.iter().map(|r| r.encode()).eq(sorted.iter().cloned()));
+
+    // Encodings longer than the comparison window, sharing long prefixes
+    let mut vecs: Vec<ArkScale<Vec<G1>>> = Vec::new();
+    for n in [5, 6, 8] {
+        for i in 0..3 {
+            let mut v: Vec<G1> = points[..n].iter().map(|p| p.0).collect();
+            v[n - 1] = points[7 - i].0;
+            vecs.push(ArkScale(v));
+        }
+    }
+    for a in &vecs {
+        for b in &vecs {
+            assert_eq!(a.cmp(b), a.encode().cmp(&b.encode()));
+        }
+    }
+    #[cfg(feature = "std")]
+    {
+        let hashes: std::collections::HashSet<_> = vecs.iter().chain(vecs.iter()).cloned().collect();
+        assert_eq!(hashes.len(), vecs.len());
+    }
+
+    let lens: BTreeSet<ArkScaleLen<G1, 48>> = points.iter().map(|p| ArkScaleLen(p.0)).collect();
+    assert!(lens.iter().map(|l| l.encode()).eq(sorted.iter().cloned()));
+    let l = lens.first().unwrap();
+    assert_eq!(l.x, l.0.x);
+    assert_eq!(l.to_string(), ArkScale::<G1>(l.0).to_string());
+    assert_eq!(l.to_string().parse::<ArkScaleLen<G1, 48>>().unwrap(), *l);
+
+    let p = &points[0];
+    assert_eq!(p.x, p.0.x);
+    assert_eq!(p.to_string(), format!("{}", ArkScaleRef::<G1>(&p.0)));
+    assert_eq!(p.to_string(), format!("0x{:x}", p));
+    assert_eq!(p.to_string(), format!("{:#x}", p));
+    assert_eq!(p.to_string().parse::<ArkScale<G1>>().unwrap(), *p);
+    let h = ArkScale::<G1, HOST_CALL>(p.0);
+    assert_eq!(h.to_string().parse::<ArkScale<G1, HOST_CALL>>().unwrap(), h);
+    assert!(format!("{:x}", p).parse::<ArkScale<G1>>().is_err());
+    assert!(format!("{}00", p).parse::<ArkScale<G1>>().is_err());
+
+    let mut f = ArkScale::<Fr>::default();
+    assert!(f.is_zero());
+    *f += Fr::from(3u8);
+    assert_eq!(f.0, Fr::from(3u8));
+}
+
+#[cfg(all(feature = "ec", feature = "std"))]
+#[test]
+fn io_streams() {
+    use std::io::Cursor;
+    type G2 = ark_bls12_381::G2Affine;
+
+    let rng = &mut rand_core::OsRng;
+    let v: Vec<G2> = (0..16).map(|_| G2::rand(rng)).collect();
+    let a = ArkScale::<_, HOST_CALL>(v);
+
+    let mut file = Vec::new();
+    a.encode_to_writer(&mut file).unwrap();
+    ArkScaleRef::<Vec<G2>>(&a.0).encode_to_writer(&mut file).unwrap();
+    assert_eq!(file, [a.encode(), ArkScale::<_>(a.0.clone()).encode()].concat());
+
+    let mut reader = Cursor::new(file);
+    assert_eq!(ArkScale::<Vec<G2>, HOST_CALL>::decode_from_reader(&mut reader).unwrap(), a);
+    assert_eq!(ArkScale::<Vec<G2>>::decode_from_reader(&mut reader).unwrap().0, a.0);
+    assert_eq!(reader.position() as usize, reader.get_ref().len());
+
+    // Truncated reads and full writes report their I/O errors
+    let truncated = a.encode();
+    let mut reader = &truncated[..truncated.len() - 1];
+    assert!(ArkScale::<Vec<G2>, HOST_CALL>::decode_from_reader(&mut reader).is_err());
+
+    let mut full = [0u8; 100];
+    assert!(a.encode_to_writer(&mut &mut full[..]).is_err());
+}
+
+#[cfg(feature = "ec")]
+#[test]
+fn seq_iter() {
+    use ark_ec::{AffineRepr, CurveGroup};
+    use ark_std::{vec::Vec, Zero};
+    type G1 = ark_bls12_381::G1Affine;
+    type G1P = ark_bls12_381::G1Projective;
+
+    let rng = &mut rand_core::OsRng;
+    let v: Vec<G1> = (0..32).map(|_| G1::rand(rng)).collect();
+    let aggregate: G1P = v.iter().map(|p| p.into_group()).sum();
+
+    let mut e = iter_ark_to_scale_bytes::<G1, _, _>(v.iter(), WIRE).unwrap();
+    e.extend_from_slice(&[7, 7]);
+    let mut input = e.as_slice();
+    let iter = ArkScaleSeqIter::<_, G1>::new(&mut input).unwrap();
+    assert_eq!(iter.size_hint(), (1, Some(32)));
+    let sum = iter.fold(G1P::zero(), |acc, p| acc + p.unwrap());
+    assert_eq!(sum.into_affine(), aggregate.into_affine());
+    assert_eq!(input, &[7, 7]);
+
+    // Stop after the first error
+    let mut e = iter_ark_to_scale_bytes::<G1, _, _>(v.iter(), HOST_CALL).unwrap();
+    let l = ArkScale::<G1, HOST_CALL>(v[0]).encoded_size();
+    e[8 + 3 * l..8 + 4 * l].fill(0xAA);
+    let mut input = e.as_slice();
+    let mut iter = ArkScaleSeqIter::<_, G1, HOST_CALL>::new(&mut input).unwrap();
+    assert_eq!(iter.by_ref().take(3).map(Result::unwrap).collect::<Vec<_>>(), v[..3]);
+    assert!(iter.next().unwrap().is_err());
+    assert_eq!(iter.remaining(), 0);
+    assert!(iter.next().is_none());
+
+    // Length prefixes beyond the remaining input fail upon decoding.
+    let mut e = 1000u64.encode();
+    e.extend_from_slice(&ArkScale::<G1>(v[0]).encode());
+    e.extend_from_slice(&[0u8; 7]);
+    let mut input = e.as_slice();
+    let mut iter = ArkScaleSeqIter::<_, G1>::new(&mut input).unwrap();
+    assert_eq!(iter.next().unwrap().unwrap(), v[0]);
+    assert!(iter.next().unwrap().is_err());
+    assert!(iter.next().is_none());
+
+    // Zero sized elements exceed the remaining input legitimately.
+    let e = 1000u64.encode();
+    let mut input = e.as_slice();
+    let iter = ArkScaleSeqIter::<_, ()>::new(&mut input).unwrap();
+    assert_eq!(iter.map(Result::unwrap).count(), 1000);
+    assert!(input.is_empty());
+}
+
+#[cfg(feature = "ec")]
+#[test]
+fn iter_encoding() {
+    use ark_std::vec::Vec;
+    type G1 = ark_bls12_381::G1Affine;
+
+    let rng = &mut rand_core::OsRng;
+    let v: Vec<G1> = (0..16).map(|_| G1::rand(rng)).collect();
+    let expected = ArkScale::<_, HOST_CALL>(v.clone()).encode();
+
+    let mut e = Vec::new();
+    encode_iter_to::<G1, _, _, _>(&v, HOST_CALL, &mut e);
+    assert_eq!(e, expected);
+
+    let i = ArkScaleIter::<G1, _, HOST_CALL>::new(v.as_slice());
+    assert_eq!(i.encode(), expected);
+    assert_eq!(i.size_hint(), expected.len());
+    assert_eq!(i.encoded_size(), expected.len());
+
+    let doubled: Vec<G1> = v.iter().map(|p| (*p + p).into()).collect();
+    let i = ArkScaleIter::<G1, _>::new(v.iter().map(|p| G1::from(*p + p)));
+    assert_eq!(i.encode(), ArkScale::<_>(doubled).encode());
+
+    fn takes_vec<E: EncodeLike<ArkScale<Vec<G1>>>>(e: E) -> Vec<u8> {
+        e.encode()
+    }
+    assert_eq!(takes_vec(ArkScaleIter::new(&v[..0])), 0u64.encode());
+}
+
+#[cfg(feature = "ec")]
+#[test]
+#[should_panic(expected = "ExactSizeIterator::len was wrong")]
+fn iter_encoding_wrong_len() {
+    use ark_ec::AffineRepr;
+    type G1 = ark_bls12_381::G1Affine;
+
+    struct Liar(usize);
+    impl Iterator for Liar {
+        type Item = G1;
+        fn next(&mut self) -> Option<G1> {
+            self.0 = self.0.checked_sub(1)?;
+            Some(G1::generator())
+        }
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            (self.0 + 1, Some(self.0 + 1))
+        }
+    }
+    impl ExactSizeIterator for Liar {}
+
+    let mut e = ark_std::vec::Vec::new();
+    encode_iter_to::<G1, _, _, _>(Liar(3), WIRE, &mut e);
+}
+
+#[cfg(feature = "ec")]
+#[test]
+#[should_panic(expected = "ExactSizeIterator::len was wrong")]
+fn iter_encoding_endless() {
+    use ark_ec::AffineRepr;
+    type G1 = ark_bls12_381::G1Affine;
+
+    // Claims one element but never ends, so we must panic on the second.
+    struct Endless;
+    impl Iterator for Endless {
+        type Item = G1;
+        fn next(&mut self) -> Option<G1> {
+            Some(G1::generator())
+        }
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            (1, Some(1))
+        }
+    }
+    impl ExactSizeIterator for Endless {}
+
+    let mut e = ark_std::vec::Vec::new();
+    encode_iter_to::<G1, _, _, _>(Endless, WIRE, &mut e);
+}
+
+#[cfg(feature = "ec")]
+#[test]
+fn slice_view() {
+    use ark_std::vec::Vec;
+    type G1 = ark_bls12_381::G1Affine;
+
+    let rng = &mut rand_core::OsRng;
+    let v: Vec<G1> = (0..10).map(|_| G1::rand(rng)).collect();
+
+    fn check<const U: Usage>(v: &[G1]) {
+        let e = ArkScale::<_, U>(v.to_vec()).encode();
+        let view = ArkScaleSliceView::<G1, U>::new(&e).unwrap();
+        assert_eq!(view.len(), v.len());
+        for (i, p) in v.iter().enumerate() {
+            assert_eq!(view.get(i).unwrap().unwrap(), *p);
+        }
+        assert!(view.get(v.len()).is_none());
+        assert_eq!(view.iter().len(), v.len());
+        assert!(view.iter().rev().map(Result::unwrap).eq(v.iter().rev().cloned()));
+
+        assert!(ArkScaleSliceView::<G1, U>::new(&e[..e.len() - 1]).is_err());
+        assert!(ArkScaleSliceView::<G1, U>::new(&[e.as_slice(), &[0]].concat()).is_err());
+    }
+    check::<WIRE>(&v);
+    check::<HOST_CALL>(&v);
+    check::<{ make_usage(Compress::No, Validate::Yes) }>(&v);
+    assert!(ArkScaleSliceView::<G1>::new(&0u64.encode()).unwrap().is_empty());
+
+    // Corrupt elements fail alone
+    let mut e = ArkScale::<_>(v.clone()).encode();
+    let stride = ArkScaleSliceView::<G1>::stride();
+    e[8 + 4 * stride..8 + 5 * stride].fill(0xAA);
+    let view = ArkScaleSliceView::<G1>::new(&e).unwrap();
+    assert!(view.get(4).unwrap().is_err());
+    assert_eq!(view.get(5).unwrap().unwrap(), v[5]);
+    assert_eq!(view.iter().filter(Result::is_err).count(), 1);
+}
+
+#[cfg(feature = "ec")]
+#[test]
+fn lazy() {
+    use ark_std::vec::Vec;
+    use scale_info::TypeInfo;
+    type G1 = ark_bls12_381::G1Affine;
+    type G2 = ark_bls12_381::G2Affine;
+
+    let rng = &mut rand_core::OsRng;
+    let p = G1::rand(rng);
+    let q = G2::rand(rng);
+    let e = (ArkScale::<G1>(p), ArkScale::<G2, HOST_CALL>(q), 5u8).encode();
+
+    let mut input = e.as_slice();
+    let (lp, lq, n) = <(ArkScaleLazy<G1>, ArkScaleLazy<G2, HOST_CALL>, u8)>::decode(&mut input).unwrap();
+    assert!(input.is_empty());
+    assert_eq!(n, 5);
+    assert_eq!(lp.encoded(), ArkScale::<G1>(p).encode());
+    assert_eq!((&lp, &lq, n).encode(), e);
+    assert_eq!(*lp.get().unwrap(), p);
+    assert_eq!(*lp.get().unwrap(), p);
+    assert_eq!(lq.into_inner().unwrap(), q);
+    assert_eq!(ArkScaleLazy::<G1>::from(p), lp);
+
+    assert_eq!(ArkScaleLazy::<G2, HOST_CALL>::max_encoded_len(), ArkScale::<G2, HOST_CALL>::max_encoded_len());
+    assert_eq!(ArkScaleLazy::<G1>::type_info(), ArkScale::<G1>::type_info());
+
+    // Points outside the subgroup decode lazily, and keep their bytes,
+    // but fail upon use.
+    let bad = (1u64..)
+        .filter_map(|x| G1::get_point_from_x_unchecked(x.into(), false))
+        .find(|p| !p.is_in_correct_subgroup_assuming_on_curve())
+        .unwrap();
+    let mut bad_bytes = Vec::new();
+    bad.serialize_compressed(&mut bad_bytes).unwrap();
+    assert!(ArkScale::<G1>::decode(&mut bad_bytes.as_slice()).is_err());
+    let lazy = ArkScaleLazy::<G1>::decode(&mut bad_bytes.as_slice()).unwrap();
+    assert!(lazy.get().is_err());
+    assert_eq!(lazy.encode(), bad_bytes);
+    assert!(ArkScaleLazy::<G1>::decode(&mut &e[..10]).is_err());
+
+    // `ArkScale::skip` does not validate, so it skips the same bad point.
+    let mut input = bad_bytes.as_slice();
+    ArkScale::<G1>::skip(&mut input).unwrap();
+    assert!(input.is_empty());
+
+    fn is_sync<S: Sync>() {}
+    is_sync::<ArkScaleLazy<G1>>();
+}
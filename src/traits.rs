@@ -0,0 +1,258 @@
+//! Standard library traits for `ArkScale`, `ArkScaleRef`, and `ArkScaleLen`
+//!
+//! We define `Hash`, `PartialOrd`, and `Ord` over the encoding in usage
+//! `U`, not over arkworks' own `Hash`, so these agree with `PartialEq`
+//! for any canonical serialization.  `Display` shows the encoding as
+//! `0x` prefixed hex, which `FromStr` parses back.
+//!
+//! We do not implement `Borrow<T>` because its contract demands that
+//! `Hash` and `Ord` agree with those of `T`, which ours do not.
+
+use ark_std::{
+    cmp::Ordering,
+    fmt,
+    hash::{Hash, Hasher},
+    ops::{Deref, DerefMut},
+    str::FromStr,
+    Zero,
+};
+
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, Validate};
+
+use crate::{
+    hex, is_compressed, rw::ark_error_to_scale_error, scale, ArkScale, ArkScaleLen, ArkScaleRef, Encode, Output,
+    Usage, WIRE,
+};
+
+/// Feeds an encoding into a `Hasher`, prefixed by its length like `[u8]`.
+fn hash_encoded<E: Encode, H: Hasher>(e: &E, state: &mut H) {
+    struct HashOutput<'a, H>(&'a mut H);
+
+    impl<'a, H: Hasher> Output for HashOutput<'a, H> {
+        fn write(&mut self, bytes: &[u8]) {
+            self.0.write(bytes)
+        }
+    }
+
+    state.write_usize(e.encoded_size());
+    e.encode_to(&mut HashOutput(state));
+}
+
+/// Compares encodings lexicographically, encoding each side once.
+fn cmp_encoded<E: Encode>(a: &E, b: &E) -> Ordering {
+    a.encode().cmp(&b.encode())
+}
+
+/// `0x` prefixed unless `{:x}` lacks the alternate flag
+fn fmt_hex<T: CanonicalSerialize, const U: Usage>(t: &T, f: &mut fmt::Formatter, prefix: bool) -> fmt::Result {
+    let s = hex::to_hex(&ArkScaleRef::<T, U>(t).encode());
+    f.write_str(if prefix { &s } else { &s[2..] })
+}
+
+// ArkScale //
+
+impl<T, const U: Usage> Deref for ArkScale<T, U> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T, const U: Usage> DerefMut for ArkScale<T, U> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.0
+    }
+}
+
+impl<T, const U: Usage> AsRef<T> for ArkScale<T, U> {
+    fn as_ref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T, const U: Usage> AsMut<T> for ArkScale<T, U> {
+    fn as_mut(&mut self) -> &mut T {
+        &mut self.0
+    }
+}
+
+impl<T: Zero, const U: Usage> Default for ArkScale<T, U> {
+    fn default() -> Self {
+        ArkScale(T::zero())
+    }
+}
+
+impl<T: CanonicalSerialize, const U: Usage> Hash for ArkScale<T, U> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        hash_encoded(self, state)
+    }
+}
+
+impl<T: CanonicalSerialize + PartialEq, const U: Usage> PartialOrd for ArkScale<T, U> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(cmp_encoded(self, other))
+    }
+}
+
+impl<T: CanonicalSerialize + Eq, const U: Usage> Ord for ArkScale<T, U> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        cmp_encoded(self, other)
+    }
+}
+
+impl<T: CanonicalSerialize, const U: Usage> fmt::Display for ArkScale<T, U> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt_hex::<T, U>(&self.0, f, true)
+    }
+}
+
+impl<T: CanonicalSerialize, const U: Usage> fmt::LowerHex for ArkScale<T, U> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt_hex::<T, U>(&self.0, f, f.alternate())
+    }
+}
+
+/// Parse `0x` prefixed hex, always validating even if `U` does not,
+/// and rejecting trailing bytes.
+impl<T: CanonicalDeserialize, const U: Usage> FromStr for ArkScale<T, U> {
+    type Err = scale::Error;
+
+    fn from_str(s: &str) -> Result<Self, scale::Error> {
+        let bytes = hex::from_hex(s)?;
+        let mut reader = bytes.as_slice();
+        let t = T::deserialize_with_mode(&mut reader, is_compressed(U), Validate::Yes)
+            .map_err(ark_error_to_scale_error)?;
+        if !reader.is_empty() {
+            return Err("Arkworks deserialization left trailing bytes".into());
+        }
+        Ok(ArkScale(t))
+    }
+}
+
+// ArkScaleRef //
+
+impl<'a, T, const U: Usage> Deref for ArkScaleRef<'a, T, U> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        self.0
+    }
+}
+
+impl<'a, T, const U: Usage> AsRef<T> for ArkScaleRef<'a, T, U> {
+    fn as_ref(&self) -> &T {
+        self.0
+    }
+}
+
+impl<'a, T: PartialEq, const U: Usage> PartialEq for ArkScaleRef<'a, T, U> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<'a, T: Eq, const U: Usage> Eq for ArkScaleRef<'a, T, U> {}
+
+impl<'a, T: CanonicalSerialize, const U: Usage> Hash for ArkScaleRef<'a, T, U> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        hash_encoded(self, state)
+    }
+}
+
+impl<'a, T: CanonicalSerialize + PartialEq, const U: Usage> PartialOrd for ArkScaleRef<'a, T, U> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(cmp_encoded(self, other))
+    }
+}
+
+impl<'a, T: CanonicalSerialize + Eq, const U: Usage> Ord for ArkScaleRef<'a, T, U> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        cmp_encoded(self, other)
+    }
+}
+
+impl<'a, T: CanonicalSerialize, const U: Usage> fmt::Display for ArkScaleRef<'a, T, U> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt_hex::<T, U>(self.0, f, true)
+    }
+}
+
+impl<'a, T: CanonicalSerialize, const U: Usage> fmt::LowerHex for ArkScaleRef<'a, T, U> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt_hex::<T, U>(self.0, f, f.alternate())
+    }
+}
+
+// ArkScaleLen //
+
+impl<T, const L: usize> Deref for ArkScaleLen<T, L> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T, const L: usize> DerefMut for ArkScaleLen<T, L> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.0
+    }
+}
+
+impl<T, const L: usize> AsRef<T> for ArkScaleLen<T, L> {
+    fn as_ref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T, const L: usize> AsMut<T> for ArkScaleLen<T, L> {
+    fn as_mut(&mut self) -> &mut T {
+        &mut self.0
+    }
+}
+
+impl<T: Zero, const L: usize> Default for ArkScaleLen<T, L> {
+    fn default() -> Self {
+        ArkScaleLen(T::zero())
+    }
+}
+
+impl<T: CanonicalSerialize, const L: usize> Hash for ArkScaleLen<T, L> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        hash_encoded(self, state)
+    }
+}
+
+impl<T: CanonicalSerialize + PartialEq, const L: usize> PartialOrd for ArkScaleLen<T, L> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(cmp_encoded(self, other))
+    }
+}
+
+impl<T: CanonicalSerialize + Eq, const L: usize> Ord for ArkScaleLen<T, L> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        cmp_encoded(self, other)
+    }
+}
+
+/// `ArkScaleLen` always encodes compressed, like `WIRE`.
+impl<T: CanonicalSerialize, const L: usize> fmt::Display for ArkScaleLen<T, L> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt_hex::<T, WIRE>(&self.0, f, true)
+    }
+}
+
+impl<T: CanonicalSerialize, const L: usize> fmt::LowerHex for ArkScaleLen<T, L> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt_hex::<T, WIRE>(&self.0, f, f.alternate())
+    }
+}
+
+impl<T: CanonicalDeserialize, const L: usize> FromStr for ArkScaleLen<T, L> {
+    type Err = scale::Error;
+
+    fn from_str(s: &str) -> Result<Self, scale::Error> {
+        ArkScale::<T, WIRE>::from_str(s).map(|a| ArkScaleLen(a.0))
+    }
+}